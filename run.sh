@@ -1 +1 @@
-RUST_LOG=info cargo run -- -d 2 -a $AOC_COOKIE
+RUST_LOG=info AOC_SESSION=$AOC_COOKIE cargo run -- run -y 2025 -d 2
@@ -0,0 +1,242 @@
+// Typed answers returned by the solvers, compared numerically when they are integers
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// Integers are equal and ordered by value, whatever their variant
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Integer(i64),
+    /// Integer too large for an `i64`, `From` never builds one that fits
+    BigInteger(i128),
+    /// Anything else, integers above `i128::MAX` included
+    Text(String),
+    /// Returned by a part still being solved, the runner reports it as not implemented
+    NotImplemented,
+}
+
+/// Value of an integer answer, negative ones ordered first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Number {
+    Negative(i128),
+    NonNegative(u128),
+}
+
+impl Number {
+    fn of(value: i128) -> Number {
+        match u128::try_from(value) {
+            Ok(value) => Number::NonNegative(value),
+            Err(_) => Number::Negative(value),
+        }
+    }
+}
+
+impl Answer {
+    /// Value of an integer answer, `None` above `i128::MAX`
+    pub fn as_integer(&self) -> Option<i128> {
+        match self.number()? {
+            Number::Negative(value) => Some(value),
+            Number::NonNegative(value) => i128::try_from(value).ok(),
+        }
+    }
+
+    fn number(&self) -> Option<Number> {
+        match self {
+            Answer::Integer(value) => Some(Number::of(*value as i128)),
+            Answer::BigInteger(value) => Some(Number::of(*value)),
+            Answer::Text(text) => match text.parse::<u128>() {
+                Ok(value) if value.to_string() == *text => Some(Number::NonNegative(value)),
+                _ => None,
+            },
+            Answer::NotImplemented => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.number(), other.number()) {
+            (Some(value), Some(other)) => value == other,
+            (None, None) => match (self, other) {
+                (Answer::Text(text), Answer::Text(other)) => text == other,
+                (Answer::NotImplemented, Answer::NotImplemented) => true,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match (self.number(), self) {
+            (Some(value), _) => value.hash(state),
+            (None, Answer::Text(text)) => text.hash(state),
+            (None, _) => state.write_u8(0),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::BigInteger(value),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    match i128::try_from(value) {
+                        Ok(value) => Answer::from(value),
+                        Err(_) => Answer::Text(value.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, u128, usize);
+
+/// Integers written without sign or leading zeros become integer answers, anything else is text
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        match value.parse::<i128>() {
+            Ok(integer) if integer.to_string() == value => Answer::from(integer),
+            _ => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::from(value.as_str())
+    }
+}
+
+/// Integers are ordered by value, other answers are only equal or not
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.number(), other.number()) {
+            (Some(value), Some(other)) => Some(value.cmp(&other)),
+            _ if self == other => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => Display::fmt(value, f),
+            Answer::BigInteger(value) => Display::fmt(value, f),
+            Answer::Text(text) => Display::fmt(text, f),
+            Answer::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+/// Compares with an answer written as text, like the stored ones
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        let other = Answer::from(other);
+        *self == other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<Answer> for String {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_integers() {
+        assert_eq!(Answer::Integer(42), Answer::from(42usize));
+        assert_eq!(Answer::Integer(-3), Answer::from(-3i32));
+        assert_eq!(Answer::BigInteger(1 << 70), Answer::from(1u128 << 70));
+        assert_eq!(Answer::Integer(7), Answer::from(7u128));
+        assert_eq!(Answer::Text(u128::MAX.to_string()), Answer::from(u128::MAX));
+    }
+
+    #[test]
+    fn test_from_text() {
+        assert_eq!(Answer::Integer(42), Answer::from("42"));
+        assert_eq!(
+            Answer::BigInteger(1 << 70),
+            Answer::from((1i128 << 70).to_string())
+        );
+        assert_eq!(Answer::Text(String::from("007")), Answer::from("007"));
+        assert_eq!(Answer::Text(String::from("+1")), Answer::from("+1"));
+        assert_eq!(Answer::Text(String::from("a,b")), Answer::from("a,b"));
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!("42", Answer::from(42u64));
+        assert_ne!("not implemented", Answer::NotImplemented);
+        assert_ne!(Answer::from(42u64), "042");
+        assert!(Answer::from(41) < Answer::Integer(42));
+        assert!(Answer::from(1u128 << 70) > Answer::from(i64::MAX));
+        assert_eq!(None, Answer::from("a").partial_cmp(&Answer::from(1)));
+        assert_eq!(
+            Some(Ordering::Equal),
+            Answer::from("a").partial_cmp(&Answer::from("a"))
+        );
+    }
+
+    #[test]
+    fn test_compare_any_variant() {
+        assert_eq!(Answer::BigInteger(5), Answer::Integer(5));
+        assert_eq!(Answer::Text(String::from("5")), Answer::Integer(5));
+        assert_ne!(Answer::Text(String::from("05")), Answer::Integer(5));
+        assert!(Answer::Integer(-1) < Answer::BigInteger(0));
+        assert!(Answer::from(u128::MAX) > Answer::from(1u128 << 70));
+        assert!(Answer::from(u128::MAX - 1) < Answer::from(u128::MAX));
+        assert_eq!(None, Answer::from(u128::MAX).as_integer());
+
+        let hash = |answer: &Answer| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            answer.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&Answer::BigInteger(5)), hash(&Answer::Integer(5)));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("12", Answer::from(12).to_string());
+        assert_eq!("a,b", Answer::from("a,b").to_string());
+        assert_eq!("not implemented", Answer::NotImplemented.to_string());
+    }
+}
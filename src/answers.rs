@@ -0,0 +1,84 @@
+// Accepted answers, kept to check solvers still find them
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+
+use crate::cache::{read_if_exists, write_creating_dirs};
+use crate::solver::Part;
+
+/// Answers stored one per line as `<year>\t<day>\t<part>\t<answer>`
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: BTreeMap<(u16, u8, Part), String>,
+}
+
+impl AnswerStore {
+    pub fn load(path: PathBuf) -> Result<AnswerStore> {
+        let mut answers = BTreeMap::new();
+        for line in read_if_exists(&path)?.unwrap_or_default().lines() {
+            if line.is_empty() {
+                continue;
+            }
+            let (key, answer) = parse_answer(line)
+                .ok_or_else(|| anyhow!("Invalid answer in {}: {}", path.display(), line))?;
+            answers.insert(key, answer);
+        }
+        Ok(AnswerStore { path, answers })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    /// Stores the answer, replacing any previous one for this part
+    pub fn set(&mut self, year: u16, day: u8, part: Part, answer: &str) -> Result<()> {
+        self.answers.insert((year, day, part), answer.to_string());
+        let content: String = self
+            .answers
+            .iter()
+            .map(|((year, day, part), answer)| format!("{}\t{}\t{}\t{}\n", year, day, part, answer))
+            .collect();
+        write_creating_dirs(&self.path, &content)
+    }
+}
+
+fn parse_answer(line: &str) -> Option<((u16, u8, Part), String)> {
+    let mut split = line.splitn(4, '\t');
+    let year = split.next()?.parse().ok()?;
+    let day = split.next()?.parse().ok()?;
+    let part = Part::from_str(split.next()?).ok()?;
+    let answer = split.next()?.to_string();
+    Some(((year, day, part), answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = AnswerStore::load(dir.path().join("answers.txt")).unwrap();
+
+        assert_eq!(None, store.get(2024, 1, Part::One));
+    }
+
+    #[test]
+    fn test_set_then_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.txt");
+        let mut store = AnswerStore::load(path.clone()).unwrap();
+
+        store.set(2024, 1, Part::One, "11").unwrap();
+        store.set(2024, 1, Part::Two, "31").unwrap();
+        store.set(2024, 1, Part::One, "12").unwrap();
+
+        let store = AnswerStore::load(path).unwrap();
+        assert_eq!(Some("12"), store.get(2024, 1, Part::One));
+        assert_eq!(Some("31"), store.get(2024, 1, Part::Two));
+        assert_eq!(None, store.get(2024, 2, Part::One));
+    }
+}
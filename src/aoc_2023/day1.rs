@@ -1,6 +1,8 @@
+use crate::answer::Answer;
+use anyhow::Result;
 use regex::Regex;
 
-pub fn day1(input: &String) -> String {
+pub fn day1(input: &str) -> Result<Answer> {
     let mut result = Vec::new();
     for coord in input.split("\n") {
         let extracted = extract_coord_digits(&coord);
@@ -8,11 +10,10 @@ pub fn day1(input: &String) -> String {
         log::info!("day1 intermediate result: {in} => {out}", in=coord, out=extracted);
     }
     log::info!("Day 1 intermediate result: {:?}", result);
-    let fold = result.iter().sum::<u16>().to_string();
-    return fold;
+    Ok(result.iter().sum::<u16>().into())
 }
 
-pub fn day1_2(input: &String) -> String {
+pub fn day1_2(input: &str) -> Result<Answer> {
     let mut result = Vec::new();
     for coord in input.split("\n") {
         let extracted = extract_coord_str(&coord);
@@ -20,8 +21,7 @@ pub fn day1_2(input: &String) -> String {
         log::info!("day1 intermediate result: {in} => {out}", in=coord, out=extracted);
     }
     log::info!("Day 1 intermediate result: {:?}", result);
-    let fold = result.iter().sum::<u16>().to_string();
-    return fold;
+    Ok(result.iter().sum::<u16>().into())
 }
 
 fn extract_coord_digits(input: &str) -> u16 {
@@ -29,12 +29,12 @@ fn extract_coord_digits(input: &str) -> u16 {
     let mut last_digit: u16 = 0;
     let mut first_found = false;
     for character in input.chars() {
-        if character.is_numeric() {
+        if let Some(digit) = character.to_digit(10) {
             if !first_found {
                 first_found = true;
-                first_digit = character.to_digit(10).unwrap() as u16;
+                first_digit = digit as u16;
             }
-            last_digit = character.to_digit(10).unwrap() as u16;
+            last_digit = digit as u16;
         }
     }
     return first_digit * 10 + last_digit;
@@ -60,21 +60,20 @@ fn extract_coord_str(input: &str) -> u16 {
     let mut text_found = String::new();
 
     for character in input.chars() {
-        if character.is_numeric() {
+        if let Some(digit) = character.to_digit(10) {
             if !first_found {
                 first_found = true;
-                first_digit = character.to_digit(10).unwrap() as u16;
+                first_digit = digit as u16;
             }
-            last_digit = character.to_digit(10).unwrap() as u16;
+            last_digit = digit as u16;
         } else {
             text_found.push(character);
-            let match_digit_str = match_digit_str(&text_found);
-            if match_digit_str.is_some() {
+            if let Some(digit) = match_digit_str(&text_found) {
                 if !first_found {
                     first_found = true;
-                    first_digit = match_digit_str.unwrap();
+                    first_digit = digit;
                 }
-                last_digit = match_digit_str.unwrap();
+                last_digit = digit;
             }
         }
     }
@@ -101,7 +100,7 @@ mod tests {
     fn test_day1() {
         let input = String::from("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet");
 
-        let result = day1(&input);
+        let result = day1(&input).unwrap();
 
         assert_eq!(String::from("142"), result);
     }
@@ -118,7 +117,7 @@ zoneight234
 7pqrstsixteen",
         );
 
-        let result = day1_2(&input);
+        let result = day1_2(&input).unwrap();
 
         assert_eq!(String::from("281"), result);
     }
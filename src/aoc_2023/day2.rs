@@ -1,8 +1,9 @@
-use log::{info, warn};
+use crate::answer::Answer;
+use crate::common::parse::{parse_lines, token, ParseError};
+use anyhow::Result;
+use log::info;
 use std::str::FromStr;
 
-use regex::Regex;
-
 #[derive(PartialEq, Eq, Hash, Debug)]
 struct Set {
     red: u8,
@@ -28,47 +29,29 @@ impl Set {
     }
 }
 impl FromStr for Set {
+    type Err = ParseError;
+
     /// Expects string as "1 red, 2 green, 6 blue"
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut red: u8 = 0;
         let mut blue: u8 = 0;
         let mut green: u8 = 0;
 
-        for color_block in input.split(", ") {
-            let mut block_split = color_block.split(" ");
-            let fst_part = block_split.next();
-            if fst_part.is_none() {
-                warn!("Invalid set pattern: {color_block}");
-                continue;
-            } else {
-                let scd_part = block_split.next();
-                if scd_part.is_none() {
-                    warn!("Invalid set pattern: {color_block}");
-                    continue;
-                }
-                let fst_part_parsed = fst_part.unwrap().parse();
-                if fst_part_parsed.is_err() {
-                    warn!("Invalid set pattern: {color_block}");
-                    continue;
-                }
-                let nbr = fst_part_parsed.unwrap();
-                let color = scd_part.unwrap();
-                if color.eq("red") {
-                    red = nbr;
-                } else if color.eq("blue") {
-                    blue = nbr;
-                } else if color.eq("green") {
-                    green = nbr;
-                } else {
-                    warn!("Invalid set pattern: {color_block}");
-                    continue;
-                }
+        for color_block in input.split(',') {
+            let color_block = color_block.trim();
+            let (count, color) = color_block
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(input, color_block, "a count and a color"))?;
+            let nbr = token(input, count, "a number of cubes")?;
+            match color {
+                "red" => red = nbr,
+                "blue" => blue = nbr,
+                "green" => green = nbr,
+                _ => return Err(ParseError::at(input, color, "red, green or blue")),
             }
         }
         Ok(Set::new(red, blue, green))
     }
-
-    type Err = String;
 }
 
 #[derive(PartialEq, Eq, Hash, Debug)]
@@ -105,49 +88,40 @@ impl Game {
 }
 
 impl FromStr for Game {
+    type Err = ParseError;
+
+    /// Expects string as "Game 1: 3 blue, 4 red; 1 red, 2 green"
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let capt = Regex::new(r"^Game (?<id>\d+):(?<sets>(( \d+ (red|green|blue),?)+;?)+)$")
-            .unwrap()
-            .captures(s);
-        if capt.is_none() {
-            return Err(format!("invalid variant: {s}"));
-        }
-        let captures = capt.unwrap();
-        let id_found = captures
-            .name("id")
-            .unwrap()
-            .as_str()
-            .parse::<u16>()
-            .unwrap();
-
-        let sets_found = captures.name("sets").unwrap().as_str();
-        warn!("sets found: {sets_found}");
-        let sets: Vec<Set> = sets_found
-            .split(";")
-            .map(str::trim)
-            .map(Set::from_str)
-            .filter(|res| res.is_ok())
-            .map(Result::unwrap)
-            .collect();
+        let (game, sets_found) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::at_end(s, "a colon"))?;
+        let id = game
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::at(s, game, "Game and its id"))?;
+        let id_found = token(s, id, "a game id")?;
+
+        let sets = sets_found
+            .split(';')
+            .map(|set| Set::from_str(set).map_err(|e| e.within(s, set)))
+            .collect::<Result<Vec<Set>, ParseError>>()?;
 
         Ok(Game::new(id_found, sets))
     }
+}
 
-    type Err = String;
+fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse_lines(input, Game::from_str)
 }
 
-pub fn day2(input: &String) -> String {
+pub fn day2(input: &str) -> Result<Answer> {
     // Puzzle constants
     // 12 red cubes, 13 green cubes, and 14 blue cubes
     const MAX_RED: u8 = 12;
     const MAX_GREEN: u8 = 13;
     const MAX_BLUE: u8 = 14;
 
-    input
-        .split("\n")
-        .map(Game::from_str)
-        .filter(Result::is_ok)
-        .map(Result::unwrap)
+    Ok(parse_games(input)?
+        .into_iter()
         .inspect(|g| info!("Found and parsed game: {:?}", g))
         .filter(|g| {
             let ok = g
@@ -160,20 +134,17 @@ pub fn day2(input: &String) -> String {
         .inspect(|g| info!("Game was deemed OK: {:?}", g))
         .map(|g| g.id)
         .sum::<u16>()
-        .to_string()
+        .into())
 }
 
-pub fn day2_2(input: &String) -> String {
-    input
-        .split("\n")
-        .map(Game::from_str)
-        .filter(Result::is_ok)
-        .map(Result::unwrap)
+pub fn day2_2(input: &str) -> Result<Answer> {
+    Ok(parse_games(input)?
+        .iter()
         .map(|g| g.min_set())
         .map(|s| s.power())
         .map(|p| p as u32)
         .sum::<u32>()
-        .to_string()
+        .into())
 }
 
 #[cfg(test)]
@@ -196,6 +167,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn game_parse_errors() {
+        assert_eq!(
+            "line 1, column 20: expected red, green or blue",
+            Game::from_str("Game 1: 3 green; 4 bleu, 1 red")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 2, column 16: expected a number of cubes",
+            day2("Game 1: 3 green\nGame 2: 3 red, x blue")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 1, column 1: expected Game and its id",
+            Game::from_str("Gme 1: 3 green").unwrap_err().to_string()
+        );
+    }
+
     #[test]
     fn part1() {
         let input = String::from(
@@ -206,7 +197,7 @@ Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
         );
 
-        assert_eq!("8", day2(&input));
+        assert_eq!("8", day2(&input).unwrap());
     }
 
     #[test]
@@ -242,6 +233,6 @@ Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
         );
 
-        assert_eq!("2286", day2_2(&input));
+        assert_eq!("2286", day2_2(&input).unwrap());
     }
 }
@@ -1,45 +1,15 @@
+use crate::answer::Answer;
+use anyhow::Result;
 use std::collections::{HashMap, HashSet};
 
 use log::info;
+use strum::IntoEnumIterator;
 
-#[derive(Hash, PartialEq, Eq, Debug)]
-struct Coordinate {
-    line: usize,
-    column: usize,
-}
-
-impl Coordinate {
-    fn new(line: usize, column: usize) -> Coordinate {
-        Coordinate { line, column }
-    }
+use crate::common::{Coordinates2D, Direction};
 
-    fn neighbours(&self) -> Vec<Coordinate> {
-        let mut neighbours = Vec::new();
-        if self.line > 0 {
-            if self.column > 0 {
-                // Top left
-                neighbours.push(Coordinate::new(self.line - 1, self.column - 1));
-            }
-            // Top
-            neighbours.push(Coordinate::new(self.line - 1, self.column));
-            // Top-right
-            neighbours.push(Coordinate::new(self.line - 1, self.column + 1));
-        }
-        if self.column > 0 {
-            // Left
-            neighbours.push(Coordinate::new(self.line, self.column - 1));
-            // Bottom-left
-            neighbours.push(Coordinate::new(self.line + 1, self.column - 1));
-        }
-        // Right
-        neighbours.push(Coordinate::new(self.line, self.column + 1));
-        // Bottom
-        neighbours.push(Coordinate::new(self.line + 1, self.column));
-        // Bottom-right
-        neighbours.push(Coordinate::new(self.line + 1, self.column + 1));
-        // And return the filled vector:
-        neighbours
-    }
+/// The 8 coordinates around, some may be out of the schematic
+fn neighbours(coordinates: &Coordinates2D) -> Vec<Coordinates2D> {
+    Direction::iter().map(|d| coordinates.advance(d)).collect()
 }
 
 #[derive(Hash, Eq, PartialEq, Debug)]
@@ -65,11 +35,11 @@ impl<T> MarkedValue<T> {
     }
 }
 
-pub fn day3(input: &String) -> String {
+pub fn day3(input: &str) -> Result<Answer> {
     let mut cur_num = String::new();
     let mut numbers = Vec::new();
-    let mut numbers_coords_index: HashMap<Coordinate, usize> = HashMap::new();
-    let mut symbol_coords: Vec<Coordinate> = Vec::new();
+    let mut numbers_coords_index: HashMap<Coordinates2D, usize> = HashMap::new();
+    let mut symbol_coords: Vec<Coordinates2D> = Vec::new();
     let mut line = 0;
     let mut column: usize = 0;
     for char in input.chars() {
@@ -80,14 +50,14 @@ pub fn day3(input: &String) -> String {
             if !cur_num.is_empty() {
                 // Then handle numeric value pass
                 // get number
-                let parsed = cur_num.parse::<usize>().unwrap();
+                let parsed = cur_num.parse::<usize>()?;
                 // store value
                 let value = MarkedValue::new(parsed);
                 numbers.push(value);
                 // store location with coordinate for indexing
                 let val_index = numbers.len() - 1;
                 for col in (column - cur_num.len() as usize)..column {
-                    numbers_coords_index.insert(Coordinate::new(line, col), val_index);
+                    numbers_coords_index.insert(Coordinates2D::new(col as isize, line), val_index);
                 }
                 cur_num = String::new();
             }
@@ -99,7 +69,7 @@ pub fn day3(input: &String) -> String {
         } else {
             if !(char == '.') && !char.is_numeric() {
                 // This a symbol ! We need to check locations
-                symbol_coords.push(Coordinate::new(line, column))
+                symbol_coords.push(Coordinates2D::new(column as isize, line))
             }
 
             // At the end on a non new line
@@ -114,7 +84,7 @@ pub fn day3(input: &String) -> String {
     let mut coord_to_use = Vec::new();
 
     for coord in symbol_coords {
-        for neighbour in coord.neighbours() {
+        for neighbour in neighbours(&coord) {
             let loc = numbers_coords_index.get(&neighbour);
             if loc.is_some() {
                 let val_opt = numbers.get_mut(*loc.unwrap());
@@ -133,15 +103,15 @@ pub fn day3(input: &String) -> String {
 
     info!("Found coordinates:\n{:?}", coord_to_use);
 
-    coord_to_use.iter().map(|f| *f).sum::<usize>().to_string()
+    Ok(coord_to_use.iter().map(|f| *f).sum::<usize>().into())
 }
 
-pub fn day3_2(input: &String) -> String {
+pub fn day3_2(input: &str) -> Result<Answer> {
     // Dirty copy paste, I know and am sorry
     let mut cur_num = String::new();
     let mut numbers = Vec::new();
-    let mut numbers_coords_index: HashMap<Coordinate, usize> = HashMap::new();
-    let mut symbol_coords: Vec<Coordinate> = Vec::new();
+    let mut numbers_coords_index: HashMap<Coordinates2D, usize> = HashMap::new();
+    let mut symbol_coords: Vec<Coordinates2D> = Vec::new();
     let mut line = 0;
     let mut column: usize = 0;
     for char in input.chars() {
@@ -152,14 +122,14 @@ pub fn day3_2(input: &String) -> String {
             if !cur_num.is_empty() {
                 // Then handle numeric value pass
                 // get number
-                let parsed = cur_num.parse::<usize>().unwrap();
+                let parsed = cur_num.parse::<usize>()?;
                 // store value
                 let value = MarkedValue::new(parsed);
                 numbers.push(value);
                 // store location with coordinate for indexing
                 let val_index = numbers.len() - 1;
                 for col in (column - cur_num.len() as usize)..column {
-                    numbers_coords_index.insert(Coordinate::new(line, col), val_index);
+                    numbers_coords_index.insert(Coordinates2D::new(col as isize, line), val_index);
                 }
                 cur_num = String::new();
             }
@@ -171,7 +141,7 @@ pub fn day3_2(input: &String) -> String {
         } else {
             if !(char == '.') && !char.is_numeric() {
                 // This a symbol ! We need to check locations
-                symbol_coords.push(Coordinate::new(line, column))
+                symbol_coords.push(Coordinates2D::new(column as isize, line))
             }
 
             // At the end on a non new line
@@ -188,7 +158,7 @@ pub fn day3_2(input: &String) -> String {
     for coord in symbol_coords {
         let mut neighboring_vals_idx = HashSet::new();
 
-        for neighbour in coord.neighbours() {
+        for neighbour in neighbours(&coord) {
             let loc = numbers_coords_index.get(&neighbour);
             if loc.is_some() {
                 neighboring_vals_idx.insert(*loc.unwrap());
@@ -206,7 +176,7 @@ pub fn day3_2(input: &String) -> String {
 
     info!("Found coordinates:\n{:?}", coord_to_use);
 
-    coord_to_use.iter().map(|f| *f).sum::<usize>().to_string()
+    Ok(coord_to_use.iter().map(|f| *f).sum::<usize>().into())
 }
 
 #[cfg(test)]
@@ -228,7 +198,7 @@ mod tests {
 .664.598..",
         );
 
-        assert_eq!("4361", day3(&input));
+        assert_eq!("4361", day3(&input).unwrap());
     }
 
     #[test_log::test]
@@ -246,7 +216,7 @@ mod tests {
 .664.598..",
         );
 
-        assert_eq!("4361", day3(&input));
+        assert_eq!("4361", day3(&input).unwrap());
     }
 
     #[test_log::test]
@@ -264,7 +234,7 @@ mod tests {
 .664.598..",
         );
 
-        assert_eq!("467835", day3_2(&input));
+        assert_eq!("467835", day3_2(&input).unwrap());
     }
 
     #[test_log::test]
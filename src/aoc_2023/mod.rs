@@ -1,3 +1,23 @@
+use crate::solver::{Puzzle, Registry};
+
 pub mod day1;
 pub mod day2;
 pub mod day3;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(
+        Puzzle::new(2023, 1, "Trebuchet?!")
+            .with_part1(day1::day1)
+            .with_part2(day1::day1_2),
+    );
+    registry.register(
+        Puzzle::new(2023, 2, "Cube Conundrum")
+            .with_part1(day2::day2)
+            .with_part2(day2::day2_2),
+    );
+    registry.register(
+        Puzzle::new(2023, 3, "Gear Ratios")
+            .with_part1(day3::day3)
+            .with_part2(day3::day3_2),
+    );
+}
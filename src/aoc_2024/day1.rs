@@ -1,5 +1,9 @@
-pub fn day1(input: &String) -> String {
-    let (mut left, mut right) = parse_input(input);
+use crate::answer::Answer;
+use crate::common::parse::{parse_lines, token, ParseError};
+use anyhow::Result;
+
+pub fn day1(input: &str) -> Result<Answer> {
+    let (mut left, mut right) = parse_input(input)?;
     left.sort();
     right.sort();
 
@@ -9,72 +13,56 @@ pub fn day1(input: &String) -> String {
         .map(|(l, r)| l.abs_diff(*r))
         .sum();
 
-    sum.to_string()
+    Ok(sum.into())
 }
 
-pub fn day1_2(input: &String) -> String {
-    let (left, right) = parse_input(input);
-    left.iter()
+pub fn day1_2(input: &str) -> Result<Answer> {
+    let (left, right) = parse_input(input)?;
+    Ok(left
+        .iter()
         .map(|l| right.iter().filter(|v: &&usize| *v == l).count() * l)
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
-fn parse_input(input: &str) -> (Vec<usize>, Vec<usize>) {
-    input
-        .split("\n")
-        .map(|l| l.trim())
-        .map(|l| parse_line(l))
-        .filter(|l| l.is_some())
-        .map(|l| l.unwrap())
-        .fold(
-            (Vec::new(), Vec::new()),
-            |mut acc: (Vec<usize>, Vec<usize>), val| {
-                acc.0.push(val.0);
-                acc.1.push(val.1);
-                acc
-            },
-        )
+fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    Ok(parse_lines(input, parse_line)?.into_iter().unzip())
 }
 
-fn parse_line(l: &str) -> Option<(usize, usize)> {
-    l.split_once(" ")
-        .map(|(left, right)| (left.trim().parse().unwrap(), right.trim().parse().unwrap()))
+fn parse_line(l: &str) -> Result<(usize, usize), ParseError> {
+    let (left, right) = l
+        .trim()
+        .split_once(" ")
+        .ok_or_else(|| ParseError::at_end(l, "two location IDs"))?;
+    Ok((
+        token(l, left, "a location ID")?,
+        token(l, right, "a location ID")?,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples::fixture;
+    use crate::solver::Part;
 
     #[test]
     fn test_day1() {
-        let input = String::from(
-            "3   4
-4   3
-2   5
-1   3
-3   9
-3   3",
-        );
+        let fixture = fixture(2024, 1);
+        let example = fixture.answer(Part::One).unwrap();
 
-        let result = day1(&input);
+        let result = day1(&fixture.examples[example.example]).unwrap();
 
-        assert_eq!(String::from("11"), result);
+        assert_eq!(example.answer, result);
     }
 
     #[test]
     fn test_day1_2() {
-        let input = String::from(
-            "3   4
-4   3
-2   5
-1   3
-3   9
-3   3",
-        );
+        let fixture = fixture(2024, 1);
+        let example = fixture.answer(Part::Two).unwrap();
 
-        let result = day1_2(&input);
+        let result = day1_2(&fixture.examples[example.example]).unwrap();
 
-        assert_eq!(String::from("31"), result);
+        assert_eq!(example.answer, result);
     }
 }
@@ -1,72 +1,66 @@
-use crate::aoc_2024::common::{CharMatrix, Coordinates2D, Direction};
+use crate::answer::Answer;
+use crate::common::search::{bfs, Search};
+use crate::common::{Coordinates2D, Grid, ParseError};
+use anyhow::Result;
 #[cfg(not(test))]
 use log::info;
 // Use log crate when building application
-use std::collections::HashSet;
 #[cfg(test)]
 use std::println as info;
-use std::str::FromStr;
 
-pub fn day10(input: &String) -> String {
-    let char_matrix = CharMatrix::from_str(input).unwrap();
-    info!("Parsed input:\n{}", char_matrix.print());
-    char_matrix
-        .search_chars(&'0')
-        .iter()
-        .map(|x| {
-            println!("New exploration starting at: {:?}", x);
-            explore(&char_matrix, -1, x)
-                .into_iter()
-                .collect::<HashSet<Coordinates2D>>()
-                .iter()
-                .count()
-        })
+pub fn day10(input: &str) -> Result<Answer> {
+    let map = parse_map(input)?;
+    info!("Parsed input:\n{}", input);
+    Ok(map
+        .positions(|height| *height == Some(0))
+        .map(|x| summits(&map, &explore(&map, x)).count())
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
-pub fn day10_2(input: &String) -> String {
-    let char_matrix = CharMatrix::from_str(input).unwrap();
-    info!("Parsed input:\n{}", char_matrix.print());
-    char_matrix
-        .search_chars(&'0')
-        .iter()
+pub fn day10_2(input: &str) -> Result<Answer> {
+    let map = parse_map(input)?;
+    info!("Parsed input:\n{}", input);
+    Ok(map
+        .positions(|height| *height == Some(0))
         .map(|x| {
-            println!("New exploration starting at: {:?}", x);
-            explore(&char_matrix, -1, x).iter().count()
+            let trails = explore(&map, x);
+            summits(&map, &trails)
+                .map(|summit| trails.count_paths(summit))
+                .sum::<usize>()
         })
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
-fn explore(
-    map: &CharMatrix,
-    previous_level: isize,
-    location: &Coordinates2D,
-) -> Vec<Coordinates2D> {
-    let level_opt = map.get_char_at(location);
-    if level_opt.is_err() {
-        return vec![];
-    }
-    let level = char::to_digit(level_opt.unwrap(), 10).unwrap() as isize;
-    if level != previous_level + 1 {
-        return vec![];
-    }
-    println!(
-        "Exploring level (prev: {}) {} at {:?}",
-        previous_level, level, location
-    );
+/// Height of each tile, `None` for the impassable ones like '.'
+fn parse_map(input: &str) -> Result<Grid<Option<u32>>, ParseError> {
+    Grid::parse(input, "a height or '.'", |c| match c {
+        '.' => Some(None),
+        c => c.to_digit(10).map(Some),
+    })
+}
 
-    if level == 9 {
-        return vec![location.clone()];
-    }
+/// Trails climbing one level at each step from the trailhead, all of them being shortest paths
+fn explore(map: &Grid<Option<u32>>, trailhead: Coordinates2D) -> Search<Coordinates2D> {
+    info!("New exploration starting at: {:?}", trailhead);
+    bfs(trailhead, |location| {
+        let level = map[*location].map(|level| level + 1);
+        map.neighbours4(location)
+            .filter(|(_, next)| **next == level)
+            .map(|(next, _)| next)
+            .collect::<Vec<_>>()
+    })
+}
 
-    explore(map, level, &Direction::North.advance(location))
-        .into_iter()
-        .chain(explore(map, level, &Direction::East.advance(location)).into_iter())
-        .chain(explore(map, level, &Direction::South.advance(location)).into_iter())
-        .chain(explore(map, level, &Direction::West.advance(location)).into_iter())
-        .collect()
+fn summits<'a>(
+    map: &'a Grid<Option<u32>>,
+    trails: &'a Search<Coordinates2D>,
+) -> impl Iterator<Item = &'a Coordinates2D> {
+    trails
+        .distances()
+        .keys()
+        .filter(|location| map[**location] == Some(9))
 }
 
 #[cfg(test)]
@@ -88,7 +82,7 @@ mod tests {
     fn test_day10() {
         let input = String::from(PUZZLE_INPUT);
 
-        let result = day10(&input);
+        let result = day10(&input).unwrap();
 
         assert_eq!(String::from("36"), result);
     }
@@ -98,7 +92,7 @@ mod tests {
     fn test_day10_2() {
         let input = String::from(PUZZLE_INPUT);
 
-        let result = day10_2(&input);
+        let result = day10_2(&input).unwrap();
 
         assert_eq!(String::from("81"), result);
     }
@@ -1,22 +1,38 @@
-use crate::aoc_2024::common::CharMatrix;
+use crate::answer::Answer;
+use crate::common::regions::Regions;
+use crate::common::{Grid, ParseError};
+use anyhow::Result;
 #[cfg(not(test))]
 use log::info;
 // Use log crate when building application
 #[cfg(test)]
 use std::println as info;
-use std::str::FromStr;
 
-pub fn day12(input: &String) -> String {
-    let char_matrix = CharMatrix::from_str(input).unwrap();
-    info!("Parsed input:\n{}", char_matrix.print());
+/// Fences around each region cost its area times its perimeter
+pub fn day12(input: &str) -> Result<Answer> {
+    let regions = parse_regions(input)?;
+    Ok(regions
+        .iter()
+        .map(|r| r.area() * r.perimeter())
+        .sum::<usize>()
+        .into())
+}
 
-    String::from("NYI")
+/// With the bulk discount, fences cost the area times the number of sides
+pub fn day12_2(input: &str) -> Result<Answer> {
+    let regions = parse_regions(input)?;
+    Ok(regions
+        .iter()
+        .map(|r| r.area() * r.sides())
+        .sum::<usize>()
+        .into())
 }
 
-pub fn day12_2(input: &String) -> String {
-    let char_matrix = CharMatrix::from_str(input).unwrap();
-    info!("Parsed input:\n{}", char_matrix.print());
-    String::from("NYI")
+fn parse_regions(input: &str) -> Result<Regions<char>, ParseError> {
+    let garden = Grid::parse(input, "a plant", |c| c.is_ascii_uppercase().then_some(c))?;
+    let regions = Regions::of(&garden);
+    info!("{} regions in the garden", regions.len());
+    Ok(regions)
 }
 
 #[cfg(test)]
@@ -42,35 +58,16 @@ MMMISSJEEE
 ";
 
     #[test]
-    #[test_log::test]
-    #[ignore]
-    fn test_day10_small() {
-        let input = String::from(PUZZLE_INPUT_SMALL);
-
-        let result = day12(&input);
-
-        assert_eq!(String::from("140"), result);
-    }
-
-    #[test]
-    #[test_log::test]
-    #[ignore]
-    fn test_day10_big() {
-        let input = String::from(PUZZLE_INPUT_BIG);
-
-        let result = day12(&input);
-
-        assert_eq!(String::from("1930"), result);
+    fn test_day12() {
+        assert_eq!("140", day12("AAAA\nBBCD\nBBCC\nEEEC\n").unwrap());
+        assert_eq!("772", day12(PUZZLE_INPUT_SMALL).unwrap());
+        assert_eq!("1930", day12(PUZZLE_INPUT_BIG).unwrap());
     }
 
     #[test]
-    #[test_log::test]
-    #[ignore]
-    fn test_day10_2() {
-        let input = String::from(PUZZLE_INPUT_BIG);
-
-        let result = day12_2(&input);
-
-        assert_eq!(String::from("81"), result);
+    fn test_day12_2() {
+        assert_eq!("80", day12_2("AAAA\nBBCD\nBBCC\nEEEC\n").unwrap());
+        assert_eq!("436", day12_2(PUZZLE_INPUT_SMALL).unwrap());
+        assert_eq!("1206", day12_2(PUZZLE_INPUT_BIG).unwrap());
     }
 }
@@ -1,4 +1,7 @@
-use crate::aoc_2024::common::{CharMatrix, Coordinates2D, Vector};
+use crate::answer::Answer;
+use crate::common::parse::{parse_blocks, token, ParseError};
+use crate::common::{Coordinates2D, Vector};
+use anyhow::Result;
 #[cfg(not(test))]
 use log::info;
 // Use log crate when building application
@@ -6,28 +9,18 @@ use log::info;
 use std::println as info;
 use std::str::FromStr;
 
-pub fn day13(input: &String) -> String {
-    let machines = parse_machines(input);
+pub fn day13(input: &str) -> Result<Answer> {
+    let machines = parse_machines(input)?;
     info!("Parsed input:\n{:?}", machines);
-    machines
+    Ok(machines
         .iter()
         .filter_map(Machine::solve)
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
-pub fn day13_2(input: &String) -> String {
-    let char_matrix = CharMatrix::from_str(input).unwrap();
-    info!("Parsed input:\n{}", char_matrix.print());
-    String::from("NYI")
-}
-
-fn parse_machines(input: &String) -> Vec<Machine> {
-    input
-        .split("\n\n")
-        .map(Machine::from_str)
-        .map(Result::unwrap)
-        .collect()
+fn parse_machines(input: &str) -> Result<Vec<Machine>, ParseError> {
+    parse_blocks(input, Machine::from_str)
 }
 
 #[derive(Debug, Clone)]
@@ -38,26 +31,26 @@ struct Machine {
 }
 
 impl FromStr for Machine {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split("\n");
-
-        let vector_a = Self::parse_button(split.next().unwrap());
-        let vector_b = Self::parse_button(split.next().unwrap());
-
-        let prize = split.next();
-        let mut prize_iter = prize.unwrap().split(' ').skip(1);
-        let prize_x: isize = prize_iter.next().unwrap()[2..]
-            .strip_suffix(",")
-            .unwrap()
-            .parse()
-            .unwrap();
-        let prize_y: isize = prize_iter.next().unwrap()[2..].parse().unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+        let parse_line = |index: usize, label: &str| {
+            let line = lines.get(index).ok_or(ParseError {
+                line: index + 1,
+                column: 1,
+                expected: format!("\"{}\"", label),
+            })?;
+            Self::parse_values(line, label).map_err(|e| e.on_line(index + 1))
+        };
+
+        let (a_x, a_y) = parse_line(0, "Button A: ")?;
+        let (b_x, b_y) = parse_line(1, "Button B: ")?;
+        let (prize_x, prize_y) = parse_line(2, "Prize: ")?;
 
         Ok(Machine::new(
-            vector_a,
-            vector_b,
+            Vector::new(a_x, a_y),
+            Vector::new(b_x, b_y),
             Coordinates2D::new(prize_x, prize_y),
         ))
     }
@@ -94,14 +87,18 @@ impl Machine {
         Some(x as usize * 3 + y as usize)
     }
 
-    fn parse_button(button_raw: &str) -> Vector {
-        let mut split_a = button_raw.split(" ").skip(2);
-        let option = split_a.next();
-        let split_a_x_str = option.unwrap().strip_suffix(",").unwrap();
-        let split_a_x: isize = split_a_x_str[2..].parse().unwrap();
-        let split_a_y_str = split_a.next().unwrap();
-        let split_a_y: isize = split_a_y_str[2..].parse().unwrap();
-        Vector::new(split_a_x, split_a_y)
+    /// Values of a line like "Button A: X+94, Y+34" or "Prize: X=8400, Y=5400"
+    fn parse_values(line: &str, label: &str) -> Result<(isize, isize), ParseError> {
+        let values = line
+            .strip_prefix(label)
+            .ok_or_else(|| ParseError::at(line, line, &format!("\"{}\"", label)))?;
+        let (x, y) = values
+            .split_once(", ")
+            .ok_or_else(|| ParseError::at_end(line, "\", \""))?;
+        // skip the "X+" or "Y="
+        let x = token(line, &x[x.len().min(2)..], "an X value")?;
+        let y = token(line, &y[y.len().min(2)..], "a Y value")?;
+        Ok((x, y))
     }
 }
 
@@ -126,13 +123,29 @@ Button B: X+27, Y+71
 Prize: X=18641, Y=10279
 ";
 
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            "line 6, column 19: expected a Y value",
+            day13(&PUZZLE_INPUT_SMALL.replace("Y+21", "Y+2x"))
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 3, column 1: expected \"Prize: \"",
+            day13("Button A: X+94, Y+34\nButton B: X+22, Y+67\n")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
     #[test]
     #[test_log::test]
     #[ignore]
     fn test_day13_small() {
         let input = String::from(PUZZLE_INPUT_SMALL);
 
-        let result = day13(&input);
+        let result = day13(&input).unwrap();
 
         assert_eq!(String::from("140"), result);
     }
@@ -1,17 +1,21 @@
-pub fn day2(input: &String) -> String {
-    parse_input(input)
+use crate::answer::Answer;
+use crate::common::parse::{parse_lines, token, ParseError};
+use anyhow::Result;
+
+pub fn day2(input: &str) -> Result<Answer> {
+    Ok(parse_input(input)?
         .iter()
         .filter(|report| Report::is_valid(report))
         .count()
-        .to_string()
+        .into())
 }
 
-pub fn day2_2(input: &String) -> String {
-    parse_input(input)
+pub fn day2_2(input: &str) -> Result<Answer> {
+    Ok(parse_input(input)?
         .iter()
         .filter(|report| Report::is_valid_with_dampener(report))
         .count()
-        .to_string()
+        .into())
 }
 
 #[derive(Debug)]
@@ -20,15 +24,16 @@ struct Report {
 }
 
 impl Report {
-    fn parse(input: &str) -> Option<Self> {
+    /// Reports with less than two levels are ignored
+    fn parse(input: &str) -> Result<Option<Self>, ParseError> {
         let levels: Vec<u8> = input
             .split_whitespace()
-            .map(|l| l.parse().unwrap())
-            .collect();
+            .map(|l| token(input, l, "a level"))
+            .collect::<Result<_, _>>()?;
         if levels.len() > 1 {
-            Some(Report { levels })
+            Ok(Some(Report { levels }))
         } else {
-            None
+            Ok(None)
         }
     }
 
@@ -82,13 +87,11 @@ impl Report {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Report> {
-    input
-        .split("\n")
-        .map(|l| Report::parse(l))
-        .filter(Option::is_some)
-        .map(Option::unwrap)
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<Report>, ParseError> {
+    Ok(parse_lines(input, Report::parse)?
+        .into_iter()
+        .flatten()
+        .collect())
 }
 
 #[cfg(test)]
@@ -106,7 +109,7 @@ mod tests {
 1 3 6 7 9",
         );
 
-        let result = day2(&input);
+        let result = day2(&input).unwrap();
 
         assert_eq!(String::from("2"), result);
     }
@@ -122,7 +125,7 @@ mod tests {
 1 3 6 7 9",
         );
 
-        let result = day2_2(&input);
+        let result = day2_2(&input).unwrap();
 
         assert_eq!(String::from("4"), result);
     }
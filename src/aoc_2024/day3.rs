@@ -1,12 +1,14 @@
+use crate::answer::Answer;
+use anyhow::Result;
 use regex::{Captures, Regex};
 use std::fmt::Debug;
 
-pub fn day3(input: &String) -> String {
+pub fn day3(input: &str) -> Result<Answer> {
     let mut parser = OperationsParser::new();
     for char in input.chars() {
         parser.push(char);
     }
-    parser.sum_results().to_string()
+    Ok(parser.sum_results().into())
 }
 
 #[derive(Debug)]
@@ -62,7 +64,7 @@ impl OperationBuilder {
 
 fn new_mul_builder() -> OperationBuilder {
     let build_regexp = Regex::new(r"^m(u(l(\((\d{0,3}(,(\d{1,3}(\)?)?)?)?)?)?)?)?$").unwrap();
-    let match_regexp = Regex::new(r"^mul\((\d{0,3}),(\d{1,3})\)$").unwrap();
+    let match_regexp = Regex::new(r"^mul\((\d{1,3}),(\d{1,3})\)$").unwrap();
     OperationBuilder {
         build_regexp,
         match_regexp,
@@ -154,7 +156,7 @@ mod tests {
         let input =
             String::from("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))");
 
-        let result = day3(&input);
+        let result = day3(&input).unwrap();
 
         assert_eq!(String::from("161"), result);
     }
@@ -165,7 +167,7 @@ mod tests {
             "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
         );
 
-        let result = day3(&input);
+        let result = day3(&input).unwrap();
 
         assert_eq!(String::from("48"), result);
     }
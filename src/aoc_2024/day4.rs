@@ -1,10 +1,12 @@
-use crate::aoc_2024::common::CharMatrix;
+use crate::answer::Answer;
+use crate::common::CharMatrix;
+use anyhow::Result;
 use std::str::FromStr;
 
-pub fn day4(input: &String) -> String {
-    let matrix = CharMatrix::from_str(input).unwrap();
+pub fn day4(input: &str) -> Result<Answer> {
+    let matrix = CharMatrix::from_str(input)?;
     println!("Matrix parsed:\n{:?}", matrix);
-    matrix.search_text().to_string()
+    Ok(matrix.search_text().into())
 }
 
 #[cfg(test)]
@@ -27,7 +29,7 @@ MAMMMXMMMM
 MXMXAXMASX",
         );
 
-        let result = day4(&input);
+        let result = day4(&input).unwrap();
 
         assert_eq!(String::from("18"), result);
     }
@@ -48,7 +50,7 @@ M.M.M.M.M.
 ",
         );
 
-        let result = day4(&input);
+        let result = day4(&input).unwrap();
 
         assert_eq!(String::from("9"), result);
     }
@@ -1,19 +1,22 @@
+use crate::answer::Answer;
+use crate::common::parse::{parse_lines, token, ParseError};
+use anyhow::Result;
 use std::fmt::Debug;
 use std::str::FromStr;
 
-pub fn day5(input: &String) -> String {
-    let (rules, updates) = parse_input(input);
-    updates
+pub fn day5(input: &str) -> Result<Answer> {
+    let (rules, updates) = parse_input(input)?;
+    Ok(updates
         .iter()
         .filter(|update: &&Update| rules.matches(update))
         .map(|update| update.get_center_pages())
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
-pub fn day5_2(input: &String) -> String {
-    let (rules, mut updates) = parse_input(input);
-    updates
+pub fn day5_2(input: &str) -> Result<Answer> {
+    let (rules, mut updates) = parse_input(input)?;
+    Ok(updates
         .iter_mut()
         .filter(|update: &&mut Update| !rules.matches(update))
         .map(|update: &mut Update| {
@@ -25,7 +28,7 @@ pub fn day5_2(input: &String) -> String {
         })
         .map(|update| update.get_center_pages())
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
 #[derive(Debug)]
@@ -35,13 +38,15 @@ struct Rule {
 }
 
 impl FromStr for Rule {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let split = input.split_once("|").unwrap();
+        let (before, after) = input
+            .split_once("|")
+            .ok_or_else(|| ParseError::at_end(input, "a |"))?;
         Ok(Rule {
-            before: usize::from_str(split.0).unwrap(),
-            after: usize::from_str(split.1).unwrap(),
+            before: token(input, before, "a page")?,
+            after: token(input, after, "a page")?,
         })
     }
 }
@@ -52,17 +57,13 @@ struct Update {
 }
 
 impl FromStr for Update {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        if input.is_empty() {
-            return Err(());
-        }
         let pages: Vec<usize> = input
             .split(",")
-            .map(usize::from_str)
-            .map(Result::unwrap)
-            .collect();
+            .map(|page| token(input, page, "a page"))
+            .collect::<Result<_, _>>()?;
         Ok(Update { pages })
     }
 }
@@ -144,16 +145,14 @@ impl Matches for Vec<Rule> {
     }
 }
 
-fn parse_input(input: &String) -> (Vec<Rule>, Vec<Update>) {
-    let parts: Vec<&str> = input.split("\n\n").collect();
-    (
-        parse_lines(parts[0], Rule::from_str),
-        parse_lines(parts[1], Update::from_str),
-    )
-}
-
-fn parse_lines<Type>(input: &str, parser: fn(&str) -> Result<Type, ()>) -> Vec<Type> {
-    input.lines().map(parser).map(Result::unwrap).collect()
+fn parse_input(input: &str) -> Result<(Vec<Rule>, Vec<Update>), ParseError> {
+    let (rules, updates) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(input, "an empty line after the rules"))?;
+    Ok((
+        parse_lines(rules, Rule::from_str)?,
+        parse_lines(updates, Update::from_str).map_err(|e| e.on_line(rules.lines().count() + 2))?,
+    ))
 }
 
 #[cfg(test)]
@@ -194,7 +193,7 @@ mod tests {
     fn test_day5() {
         let input = String::from(PUZZLE_INPUT);
 
-        let result = day5(&input);
+        let result = day5(&input).unwrap();
 
         assert_eq!(String::from("143"), result);
     }
@@ -202,8 +201,21 @@ mod tests {
     #[test]
     fn test_day5_2() {
         let input = String::from(PUZZLE_INPUT);
-        let result = day5_2(&input);
+        let result = day5_2(&input).unwrap();
 
         assert_eq!(String::from("123"), result);
     }
+
+    #[test]
+    fn test_day5_invalid_input() {
+        assert_eq!(
+            "line 2, column 4: expected a page",
+            day5("47|53\n97|x3\n\n75,47\n").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 4, column 4: expected a page",
+            day5("47|53\n97|13\n\n75,4x\n").unwrap_err().to_string()
+        );
+        assert!(day5("47|53\n").is_err());
+    }
 }
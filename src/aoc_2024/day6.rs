@@ -1,32 +1,32 @@
-use crate::aoc_2024::common::Direction::{East, North, South, West};
-use crate::aoc_2024::common::{CharMatrix, Coordinates2D, Direction};
+use crate::answer::Answer;
+use crate::common::Direction::{East, North, South, West};
+use crate::common::{CharMatrix, Coordinates2D, Direction};
+use anyhow::{anyhow, Result};
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::str::FromStr;
 
-pub fn day6(input: &String) -> String {
-    let map = CharMatrix::from_str(input).unwrap();
+pub fn day6(input: &str) -> Result<Answer> {
+    let map = CharMatrix::from_str(input)?;
 
     // now we have our map and guards, let's draw our patrol
 
-    let visited_locations = get_guard_path(&map);
+    let visited_locations = get_guard_path(&map)?;
 
-    visited_locations.len().to_string()
+    Ok(visited_locations.len().into())
 }
 
-fn get_guard_path(map: &CharMatrix) -> HashSet<Coordinates2D> {
-    let mut guard = find_guard(&map);
+fn get_guard_path(map: &CharMatrix) -> Result<HashSet<Coordinates2D>> {
+    let mut guard = find_guard(&map)?;
     let mut visited_locations: HashSet<Coordinates2D> = HashSet::new();
     let coordinates = guard.location.clone();
     visited_locations.insert(coordinates);
     while map.is_in_bounds(&guard.location) {
         let next_step = guard.next_step();
-        let result = map.get_char_at(&next_step);
-        if result.is_err() {
+        let Some(next_step_content) = map.get_char_at(&next_step) else {
             println!("Guard went out of bound: {:?}", guard);
             break;
-        }
-        let next_step_content = result.unwrap();
+        };
         match next_step_content {
             '#' => guard.turn_right(),
             _ => guard.advance(),
@@ -34,34 +34,34 @@ fn get_guard_path(map: &CharMatrix) -> HashSet<Coordinates2D> {
         println!("Guard located: {:?}", guard);
         visited_locations.insert(guard.location.clone());
     }
-    visited_locations
+    Ok(visited_locations)
 }
 
-fn find_guard(map: &CharMatrix) -> Guard {
+fn find_guard(map: &CharMatrix) -> Result<Guard> {
     let guard_char = map
         .search_char(&'^')
         .or_else(|| {
             map.search_char(&'>')
                 .or_else(|| map.search_char(&'v').or_else(|| map.search_char(&'<')))
         })
-        .unwrap();
+        .ok_or_else(|| anyhow!("No guard (^, >, v or <) on the map"))?;
 
     let guard = Guard::new(
         guard_char,
         Direction::from(map.get_char_at(&guard_char).unwrap()),
     );
-    guard
+    Ok(guard)
 }
 
 const MAP_OBJECTS: [char; 5] = ['#', '^', '>', 'v', '<'];
 
-pub fn day6_2(input: &String) -> String {
-    let map = CharMatrix::from_str(input).unwrap();
-    let guard = find_guard(&map);
+pub fn day6_2(input: &str) -> Result<Answer> {
+    let map = CharMatrix::from_str(input)?;
+    let guard = find_guard(&map)?;
     println!("Guard found: {:?}", guard);
 
     // get guard path to avoid repetition
-    let visited_locations = get_guard_path(&map);
+    let visited_locations = get_guard_path(&map)?;
 
     // now we have our map and guards, let's drw our patrol
     let mut loop_count: usize = 0;
@@ -78,7 +78,7 @@ pub fn day6_2(input: &String) -> String {
             }
         }
     }
-    loop_count.to_string()
+    Ok(loop_count.into())
 }
 
 fn is_map_loop(map: &CharMatrix, guard_original: &Guard) -> bool {
@@ -89,12 +89,10 @@ fn is_map_loop(map: &CharMatrix, guard_original: &Guard) -> bool {
     visited_locations.insert((guard.location.to_owned(), guard.direction.to_owned()));
     while map.is_in_bounds(&guard.location) {
         let next_step = guard.next_step();
-        let result = map.get_char_at(&next_step);
-        if result.is_err() {
+        let Some(next_step_content) = map.get_char_at(&next_step) else {
             // guard is out of map, we are not in a loop
             return false;
-        }
-        let next_step_content = result.unwrap();
+        };
         match next_step_content {
             '#' => guard.turn_right(),
             _ => guard.advance(),
@@ -170,7 +168,7 @@ mod tests {
     fn test_day6() {
         let input = String::from(PUZZLE_INPUT);
 
-        let result = day6(&input);
+        let result = day6(&input).unwrap();
 
         assert_eq!(String::from("41"), result);
     }
@@ -178,7 +176,7 @@ mod tests {
     #[test]
     fn test_day6_2() {
         let input = String::from(PUZZLE_INPUT);
-        let result = day6_2(&input);
+        let result = day6_2(&input).unwrap();
 
         assert_eq!(String::from("6"), result);
     }
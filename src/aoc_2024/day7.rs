@@ -1,28 +1,24 @@
+use crate::answer::Answer;
 use crate::aoc_2024::day7::Operator::{ADD, CONCAT, MULTIPLY};
+use crate::common::parse::{parse_lines, token, ParseError};
+use anyhow::Result;
 use std::fmt::Debug;
 use std::str::FromStr;
 use strum::EnumCount;
 use strum_macros::EnumCount;
 
-pub fn day7(input: &String) -> String {
-    let ops = parse_operations(input);
-    ops.iter()
+pub fn day7(input: &str) -> Result<Answer> {
+    let ops = parse_operations(input)?;
+    Ok(ops
+        .iter()
         .filter(|op| op.try_all_combinations())
         .map(Operation::get_result)
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
-pub fn day7_2(_input: &String) -> String {
-    String::from("NYI")
-}
-
-fn parse_operations(input: &String) -> Vec<Operation> {
-    input
-        .split("\n")
-        .map(Operation::from_str)
-        .filter_map(Result::ok)
-        .collect()
+fn parse_operations(input: &str) -> Result<Vec<Operation>, ParseError> {
+    parse_lines(input, Operation::from_str)
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone, EnumCount)]
@@ -118,20 +114,17 @@ impl Operation {
 }
 
 impl FromStr for Operation {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let split = input.split(":").collect::<Vec<&str>>();
-        if split.len() != 2 {
-            log::error!("Could not parse input: {:?}", input);
-            return Err(());
-        }
-        let result = split[0].parse::<usize>().unwrap();
-        let values: Vec<usize> = split[1]
-            .split(' ')
-            .map(|x| x.parse::<usize>())
-            .filter_map(Result::ok)
-            .collect();
+        let (result, values) = input
+            .split_once(":")
+            .ok_or_else(|| ParseError::at_end(input, "a colon"))?;
+        let result = token(input, result, "a result")?;
+        let values: Vec<usize> = values
+            .split_whitespace()
+            .map(|x| token(input, x, "a value"))
+            .collect::<Result<_, _>>()?;
         Ok(Operation { result, values })
     }
 }
@@ -156,7 +149,7 @@ mod tests {
     fn test_day7_2() {
         let input = String::from(PUZZLE_INPUT);
 
-        let result = day7(&input);
+        let result = day7(&input).unwrap();
 
         assert_eq!(String::from("11387"), result);
     }
@@ -169,7 +162,7 @@ mod tests {
 ",
         );
 
-        let result = day7(&input);
+        let result = day7(&input).unwrap();
 
         assert_eq!(String::from("135"), result);
     }
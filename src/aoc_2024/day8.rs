@@ -1,17 +1,19 @@
-use crate::aoc_2024::common::{CharMatrix, Coordinates2D, Rectangle, Vector};
+use crate::answer::Answer;
+use crate::common::{CharMatrix, Coordinates2D, Rectangle, Vector};
+use anyhow::Result;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
-pub fn day8(input: &String) -> String {
+pub fn day8(input: &str) -> Result<Answer> {
     find_antinodes_count(input, false)
 }
 
-pub fn day8_2(input: &String) -> String {
+pub fn day8_2(input: &str) -> Result<Answer> {
     find_antinodes_count(input, true)
 }
 
-fn find_antinodes_count(input: &String, include_resonating: bool) -> String {
-    let map = CharMatrix::from_str(input).unwrap();
+fn find_antinodes_count(input: &str, include_resonating: bool) -> Result<Answer> {
+    let map = CharMatrix::from_str(input)?;
     let distinct: HashMap<char, Vec<Coordinates2D>> = map
         .get_all_chars()
         .iter()
@@ -29,7 +31,7 @@ fn find_antinodes_count(input: &String, include_resonating: bool) -> String {
     println!("Distinct values: {:?}", distinct);
     let maps_bounds = map.get_bounds();
 
-    distinct
+    Ok(distinct
         .iter()
         .map(|(char, coords)| {
             println!("Searching all antinodes for {}: {:?}", char, coords);
@@ -58,7 +60,7 @@ fn find_antinodes_count(input: &String, include_resonating: bool) -> String {
         .iter()
         .filter(|cme| cme.value == 'X')
         .count()
-        .to_string()
+        .into())
 }
 fn get_antinodes(
     c1: &Coordinates2D,
@@ -128,7 +130,7 @@ mod tests {
     fn test_day8() {
         let input = String::from(PUZZLE_INPUT);
 
-        let result = day8(&input);
+        let result = day8(&input).unwrap();
 
         assert_eq!(String::from("14"), result);
         //    0 1 2 3 4 5 6 7 8 9 10 11
@@ -180,7 +182,7 @@ mod tests {
     fn test_day8_2() {
         let input = String::from(PUZZLE_INPUT);
 
-        let result = day8_2(&input);
+        let result = day8_2(&input).unwrap();
 
         assert_eq!(String::from("34"), result);
     }
@@ -200,7 +202,7 @@ mod tests {
 ..........",
         );
 
-        let result = day8_2(&input);
+        let result = day8_2(&input).unwrap();
 
         assert_eq!(String::from("9"), result);
     }
@@ -1,21 +1,24 @@
+use crate::answer::Answer;
 use crate::aoc_2024::day9::MemoryType::{File, Space};
+use crate::common::ParseError;
+use anyhow::Result;
 use std::collections::HashSet;
 use std::str::FromStr;
 
-pub fn day9(input: &String) -> String {
-    let memory_map = MemoryMap::from_str(input).unwrap();
+pub fn day9(input: &str) -> Result<Answer> {
+    let memory_map = MemoryMap::from_str(input)?;
     log::info!("Map parsed:    {:?}", memory_map.to_string());
     let optimized_map = memory_map.optimize(true);
     log::info!("Map optimized: {:?}", optimized_map.to_string());
-    optimized_map.checksum().to_string()
+    Ok(optimized_map.checksum().into())
 }
 
-pub fn day9_2(input: &String) -> String {
-    let mut memory_map = MemoryMap::from_str(input).unwrap();
+pub fn day9_2(input: &str) -> Result<Answer> {
+    let mut memory_map = MemoryMap::from_str(input)?;
     log::info!("Map parsed:    {:?}", memory_map.to_string());
     memory_map.optimize_v2();
     log::info!("Map optimized: {:?}", memory_map.to_string());
-    memory_map.checksum().to_string()
+    Ok(memory_map.checksum().into())
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
@@ -248,13 +251,20 @@ impl MemoryMap {
 }
 
 impl FromStr for MemoryMap {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let sizes = input
+            .trim_end()
+            .char_indices()
+            .map(|(index, c)| {
+                c.to_digit(10)
+                    .ok_or_else(|| ParseError::at(input, &input[index..], "a digit"))
+            })
+            .collect::<Result<Vec<u32>, ParseError>>()?;
         Ok(MemoryMap::new(
-            input
-                .chars()
-                .filter_map(|c| c.to_digit(10))
+            sizes
+                .into_iter()
                 .enumerate()
                 .map(|(position, block_size)| {
                     if position % 2 == 0 {
@@ -282,7 +292,7 @@ mod tests {
     fn test_day9() {
         let input = String::from(PUZZLE_INPUT);
 
-        let result = day9(&input);
+        let result = day9(&input).unwrap();
 
         assert_eq!(String::from("1928"), result);
     }
@@ -291,7 +301,7 @@ mod tests {
     fn test_day9_2() {
         let input = String::from(PUZZLE_INPUT);
 
-        let result = day9_2(&input);
+        let result = day9_2(&input).unwrap();
 
         assert_eq!(String::from("2858"), result);
     }
@@ -300,7 +310,7 @@ mod tests {
     fn test_day9_2_should_not_relocated_after() {
         let input = String::from("2010116");
 
-        let result = day9_2(&input);
+        let result = day9_2(&input).unwrap();
 
         assert_eq!(String::from("143"), result);
     }
@@ -1,13 +1,62 @@
-pub mod common;
-pub(super) mod day1;
-pub(super) mod day10;
-pub(super) mod day12;
-pub(super) mod day13;
-pub(super) mod day2;
-pub(super) mod day3;
-pub(super) mod day4;
-pub(super) mod day5;
-pub(super) mod day6;
-pub(super) mod day7;
-pub(super) mod day8;
-pub(super) mod day9;
+use crate::solver::{Puzzle, Registry};
+
+pub mod day1;
+pub mod day10;
+pub mod day12;
+pub mod day13;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(
+        Puzzle::new(2024, 1, "Historian Hysteria")
+            .with_part1(day1::day1)
+            .with_part2(day1::day1_2),
+    );
+    registry.register(
+        Puzzle::new(2024, 2, "Red-Nosed Reports")
+            .with_part1(day2::day2)
+            .with_part2(day2::day2_2),
+    );
+    // day3, day4 and day7 functions were reworked for part 2, their part 1 is lost
+    registry.register(Puzzle::new(2024, 3, "Mull It Over").with_part2(day3::day3));
+    registry.register(Puzzle::new(2024, 4, "Ceres Search").with_part2(day4::day4));
+    registry.register(
+        Puzzle::new(2024, 5, "Print Queue")
+            .with_part1(day5::day5)
+            .with_part2(day5::day5_2),
+    );
+    registry.register(
+        Puzzle::new(2024, 6, "Guard Gallivant")
+            .with_part1(day6::day6)
+            .with_part2(day6::day6_2),
+    );
+    registry.register(Puzzle::new(2024, 7, "Bridge Repair").with_part2(day7::day7));
+    registry.register(
+        Puzzle::new(2024, 8, "Resonant Collinearity")
+            .with_part1(day8::day8)
+            .with_part2(day8::day8_2),
+    );
+    registry.register(
+        Puzzle::new(2024, 9, "Disk Fragmenter")
+            .with_part1(day9::day9)
+            .with_part2(day9::day9_2),
+    );
+    registry.register(
+        Puzzle::new(2024, 10, "Hoof It")
+            .with_part1(day10::day10)
+            .with_part2(day10::day10_2),
+    );
+    registry.register(
+        Puzzle::new(2024, 12, "Garden Groups")
+            .with_part1(day12::day12)
+            .with_part2(day12::day12_2),
+    );
+    registry.register(Puzzle::new(2024, 13, "Claw Contraption").with_part1(day13::day13));
+}
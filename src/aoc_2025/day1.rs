@@ -1,53 +1,33 @@
+use crate::answer::Answer;
+use crate::common::parse::{parse_lines, token, ParseError};
+use anyhow::Result;
 use log::info;
-use std::isize;
-use std::str::FromStr;
 
-pub fn day1(input: &String) -> String {
-    let inputs = parse_input(input);
+pub fn day1(input: &str) -> Result<Answer> {
+    let inputs = parse_input(input)?;
 
     let mut dial: isize = 50;
     let mut zero_touched = 0;
-    for combi in inputs {
-        if combi.starts_with("L") {
-            let option = combi
-                .strip_prefix("L")
-                .map(|s| isize::from_str(s))
-                .map(|r| r.unwrap())
-                .unwrap();
-            dial = (dial - option) % 100;
-        } else if combi.starts_with("R") {
-            let option = combi
-                .strip_prefix("R")
-                .map(|s| isize::from_str(s))
-                .map(|r| r.unwrap())
-                .unwrap();
-            dial = (dial + option) % 100;
-        }
+    for rotation in inputs {
+        dial = (dial + rotation) % 100;
 
-        info!("Instruction: {combi}, dial={dial}");
+        info!("Instruction: {rotation}, dial={dial}");
         if dial == 0 {
             zero_touched += 1;
         }
     }
 
-    zero_touched.to_string()
+    Ok(zero_touched.into())
 }
 
-pub fn day1_2(input: &String) -> String {
-    let inputs = parse_input(input);
+pub fn day1_2(input: &str) -> Result<Answer> {
+    let inputs = parse_input(input)?;
 
     let mut dial: isize = 50;
     let mut zero_touched = 0;
-    for combi in inputs {
-        if combi.starts_with("L") {
-            let option = combi
-                .strip_prefix("L")
-                .map(|s| isize::from_str(s))
-                .map(|r| r.unwrap())
-                .unwrap_or_else(|| {
-                    panic!("Cannot parse input {combi}");
-                });
-            for _ in 0..option {
+    for rotation in inputs {
+        if rotation < 0 {
+            for _ in 0..-rotation {
                 dial = if dial == 0 { 99 } else { dial - 1 };
                 if dial == 0 {
                     zero_touched += 1;
@@ -55,14 +35,7 @@ pub fn day1_2(input: &String) -> String {
                 }
             }
         } else {
-            let option = combi
-                .strip_prefix("R")
-                .map(|s| isize::from_str(s))
-                .map(|r| r.unwrap())
-                .unwrap_or_else(|| {
-                    panic!("Cannot parse input {combi}");
-                });
-            for _ in 0..option {
+            for _ in 0..rotation {
                 dial = if dial == 99 { 0 } else { dial + 1 };
                 if dial == 0 {
                     zero_touched += 1;
@@ -71,18 +44,24 @@ pub fn day1_2(input: &String) -> String {
             }
         };
 
-        info!("Instruction: {combi}, dial={dial}");
+        info!("Instruction: {rotation}, dial={dial}");
     }
 
-    zero_touched.to_string()
+    Ok(zero_touched.into())
 }
 
-fn parse_input(input: &str) -> Vec<&str> {
-    input
-        .split("\n")
-        .map(|l| l.trim())
-        .filter(|l| !l.is_empty())
-        .collect()
+/// Parse each "L68" or "R48" line into a signed rotation, left being negative
+fn parse_input(input: &str) -> Result<Vec<isize>, ParseError> {
+    parse_lines(input, |l| {
+        let combi = l.trim();
+        if let Some(distance) = combi.strip_prefix('L') {
+            token::<isize>(l, distance, "a distance").map(|d| -d)
+        } else if let Some(distance) = combi.strip_prefix('R') {
+            token(l, distance, "a distance")
+        } else {
+            Err(ParseError::at(l, combi, "L or R"))
+        }
+    })
 }
 
 #[cfg(test)]
@@ -104,7 +83,7 @@ L82";
     fn test_day1() {
         let input = String::from(TEST_INPUT);
 
-        let result = day1(&input);
+        let result = day1(&input).unwrap();
 
         assert_eq!(String::from("3"), result);
     }
@@ -114,7 +93,7 @@ L82";
         let _ = env_logger::builder().is_test(true).try_init();
         let input = String::from(TEST_INPUT);
 
-        let result = day1_2(&input);
+        let result = day1_2(&input).unwrap();
 
         assert_eq!(String::from("6"), result);
     }
@@ -1,10 +1,12 @@
-use crate::aoc_2025::common::Range;
+use crate::answer::Answer;
+use crate::common::{ParseError, Range};
+use anyhow::Result;
 use std::cmp::min;
 use std::collections::HashSet;
 use std::str::FromStr;
 
-pub fn day2(input: &String) -> String {
-    let inputs = parse_input(input);
+pub fn day2(input: &str) -> Result<Answer> {
+    let inputs = parse_input(input)?;
     let sum: usize = inputs
         .iter()
         .map(|r| r)
@@ -12,11 +14,11 @@ pub fn day2(input: &String) -> String {
         .filter(|x| has_repeating_pattern(x, 2))
         .sum();
 
-    sum.to_string()
+    Ok(sum.into())
 }
 
-pub fn day2_2(input: &String) -> String {
-    let inputs = parse_input(input);
+pub fn day2_2(input: &str) -> Result<Answer> {
+    let inputs = parse_input(input)?;
     let sum: usize = inputs
         .iter()
         .map(|r| r)
@@ -24,7 +26,7 @@ pub fn day2_2(input: &String) -> String {
         .filter(|x| has_repeating_pattern(x, usize::MAX))
         .sum();
 
-    sum.to_string()
+    Ok(sum.into())
 }
 
 fn get_all_values(input: &Range) -> Vec<usize> {
@@ -57,17 +59,11 @@ fn has_repeating_pattern(input: &usize, max_limit: usize) -> bool {
     false
 }
 
-fn parse_input(input: &str) -> Vec<Range> {
+fn parse_input(input: &str) -> Result<Vec<Range>, ParseError> {
     input
         .split(",")
-        .map(|l| l.trim())
-        .filter(|l| !l.is_empty())
-        .map(|s| {
-            let sp: Vec<&str> = s.split('-').collect();
-            let start = usize::from_str(sp[0]).unwrap();
-            let end = usize::from_str(sp[1]).unwrap();
-            Range { start, end }
-        })
+        .filter(|l| !l.trim().is_empty())
+        .map(|s| Range::from_str(s).map_err(|e| e.within(input, s)))
         .collect()
 }
 
@@ -83,7 +79,7 @@ mod tests {
     fn test_day1() {
         let input = String::from(TEST_INPUT);
 
-        let result = day2(&input);
+        let result = day2(&input).unwrap();
 
         assert_eq!(String::from("1227775554"), result);
     }
@@ -93,7 +89,7 @@ mod tests {
         let _ = env_logger::builder().is_test(true).try_init();
         let input = String::from(TEST_INPUT);
 
-        let result = day2_2(&input);
+        let result = day2_2(&input).unwrap();
 
         assert_eq!(String::from("4174379265"), result);
     }
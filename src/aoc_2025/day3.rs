@@ -1,3 +1,6 @@
+use crate::answer::Answer;
+use crate::common::parse::{parse_lines, ParseError};
+use anyhow::Result;
 use log::debug;
 
 #[derive(Debug)]
@@ -41,40 +44,38 @@ impl PowerBank {
     }
 }
 
-pub fn day3(input: &String) -> String {
-    parse_input(input)
+pub fn day3(input: &str) -> Result<Answer> {
+    Ok(parse_input(input)?
         .iter()
         .map(|pb| pb.max_joltage(2))
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
-pub fn day3_2(input: &String) -> String {
-    parse_input(input)
+pub fn day3_2(input: &str) -> Result<Answer> {
+    Ok(parse_input(input)?
         .iter()
         .map(|pb| pb.max_joltage(12))
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
-fn parse_input(input: &str) -> Vec<PowerBank> {
-    input
-        .split("\n")
-        .map(|l| l.trim())
-        .filter(|l| !l.is_empty())
-        .map(|s| {
-            let batteries = s
-                .chars()
-                .map(|c| c.to_digit(10))
-                .map(Option::unwrap)
-                .map(|x| x as usize)
-                .collect();
-            let bank = PowerBank { batteries };
-            println!("parsed Power bank: {bank:?}");
-            debug!("parsed Power bank: {bank:?}");
-            bank
-        })
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<PowerBank>, ParseError> {
+    parse_lines(input, |l| {
+        let s = l.trim();
+        let batteries = s
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .map(|x| x as usize)
+                    .ok_or_else(|| ParseError::at(l, &s[i..i + c.len_utf8()], "a joltage digit"))
+            })
+            .collect::<Result<_, _>>()?;
+        let bank = PowerBank { batteries };
+        println!("parsed Power bank: {bank:?}");
+        debug!("parsed Power bank: {bank:?}");
+        Ok(bank)
+    })
 }
 
 #[cfg(test)]
@@ -90,7 +91,7 @@ mod tests {
     fn test_day1() {
         let input = String::from(TEST_INPUT);
 
-        let result = day3(&input);
+        let result = day3(&input).unwrap();
 
         assert_eq!(String::from("357"), result);
     }
@@ -100,7 +101,7 @@ mod tests {
         let _ = env_logger::builder().is_test(true).try_init();
         let input = String::from(TEST_INPUT);
 
-        let result = day3_2(&input);
+        let result = day3_2(&input).unwrap();
 
         assert_eq!(String::from("3121910778619"), result);
     }
@@ -1,66 +1,46 @@
-use crate::aoc_2024::common::{CharMatrix, Coordinates2D, Direction};
-use std::str::FromStr;
-use strum::IntoEnumIterator;
+use crate::answer::Answer;
+use crate::common::{Coordinates2D, Grid, ParseError};
+use anyhow::Result;
 
-pub fn day4(input: &String) -> String {
-    let matrix = parse_input(input);
-    matrix
-        .search_chars(&'@')
-        .iter()
-        .map(|x| {
-            Direction::iter()
-                .map(|dir| matrix.get_char_at(&dir.advance(x)))
-                .filter(|result| result.is_ok())
-                .map(|result| result.unwrap())
-                .filter(|result| *result == '@')
-                .count()
-        })
-        .filter(|adjacent_count| *adjacent_count < 4usize)
-        .count()
-        .to_string()
+pub fn day4(input: &str) -> Result<Answer> {
+    let grid = parse_input(input)?;
+    Ok(accessible_rolls(&grid).len().into())
 }
 
-const SLOT_FILE_PRESENT_CHAR: char = '@';
-const EMPTY_SLOT_CHAR: char = '.';
-
-pub fn day4_2(input: &String) -> String {
-    let mut matrix = parse_input(input);
+pub fn day4_2(input: &str) -> Result<Answer> {
+    let mut grid = parse_input(input)?;
 
     let mut removed_total = 0;
 
     loop {
-        let removed: Vec<Coordinates2D> = matrix
-            .search_chars(&SLOT_FILE_PRESENT_CHAR)
-            .iter()
-            .map(|x| {
-                (
-                    *x,
-                    Direction::iter()
-                        .map(|dir| matrix.get_char_at(&dir.advance(x)))
-                        .filter(|result| result.is_ok())
-                        .map(|result| result.unwrap())
-                        .filter(|result| *result == SLOT_FILE_PRESENT_CHAR)
-                        .count(),
-                )
-            })
-            .filter(|(_, adjacent_count)| *adjacent_count < 4usize)
-            .map(|(x, _)| x)
-            .collect();
+        let removed = accessible_rolls(&grid);
         if removed.is_empty() {
             break;
         } else {
             removed_total += removed.len();
             for coordinate in removed {
-                matrix.set_char(EMPTY_SLOT_CHAR, &coordinate);
+                grid[coordinate] = false;
             }
         }
     }
 
-    removed_total.to_string()
+    Ok(removed_total.into())
+}
+
+/// Rolls of paper with less than 4 rolls around them
+fn accessible_rolls(grid: &Grid<bool>) -> Vec<Coordinates2D> {
+    grid.positions(|roll| *roll)
+        .filter(|x| grid.neighbours8(x).filter(|(_, roll)| **roll).count() < 4)
+        .collect()
 }
 
-fn parse_input(input: &str) -> CharMatrix {
-    CharMatrix::from_str(input).unwrap()
+/// Whether each slot holds a roll of paper ('@') or is empty ('.')
+fn parse_input(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input, "'@' or '.'", |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
 }
 
 #[cfg(test)]
@@ -82,7 +62,7 @@ mod tests {
     fn test_day1() {
         let input = String::from(TEST_INPUT);
 
-        let result = day4(&input);
+        let result = day4(&input).unwrap();
 
         assert_eq!(String::from("13"), result);
     }
@@ -92,7 +72,7 @@ mod tests {
         let _ = env_logger::builder().is_test(true).try_init();
         let input = String::from(TEST_INPUT);
 
-        let result = day4_2(&input);
+        let result = day4_2(&input).unwrap();
 
         assert_eq!(String::from("43"), result);
     }
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::common::parse::{parse_lines, token, ParseError};
+use anyhow::{anyhow, Result};
+
 #[cfg(not(test))]
 use log::info;
 use ranges::{GenericRange, Ranges};
@@ -5,65 +9,59 @@ use std::ops::{Bound, RangeBounds};
 #[cfg(test)]
 use std::println as info;
 
-pub fn day5(input: &String) -> String {
-    let (ranges, ids) = parse_input(input);
+pub fn day5(input: &str) -> Result<Answer> {
+    let (ranges, ids) = parse_input(input)?;
 
-    ids.iter()
-        .filter(|id| ranges.contains(&id))
-        .count()
-        .to_string()
+    Ok(ids.iter().filter(|id| ranges.contains(&id)).count().into())
 }
 
-pub fn day5_2(input: &String) -> String {
-    let (ranges, _) = parse_input(input);
+pub fn day5_2(input: &str) -> Result<Answer> {
+    let (ranges, _) = parse_input(input)?;
 
-    ranges
+    Ok(ranges
         .as_slice()
         .into_iter()
         .map(|x| {
-            let start = extract(x.start_bound());
-            let end = extract(x.end_bound());
+            let start = extract(x.start_bound())?;
+            let end = extract(x.end_bound())?;
             info!("range between {start} and {end}");
-            return end - start + 1;
+            Ok(end - start + 1)
         })
-        .sum::<usize>()
-        .to_string()
+        .sum::<Result<usize>>()?
+        .into())
 }
 
-fn extract(input: Bound<&usize>) -> usize {
+fn extract(input: Bound<&usize>) -> Result<usize> {
     match input {
-        Bound::Included(included) => *included,
-        Bound::Excluded(excluded) => *excluded,
-        Bound::Unbounded => {
-            panic!()
-        }
+        Bound::Included(included) => Ok(*included),
+        Bound::Excluded(excluded) => Ok(*excluded),
+        Bound::Unbounded => Err(anyhow!("Unbounded range of IDs")),
     }
 }
 
 /// Parse Range from "123-456" pattern
-fn parse_range(s: &str) -> GenericRange<usize> {
-    let (left, right) = s.split_once("-").unwrap();
-    let start = left.parse().unwrap();
-    let end = right.parse().unwrap();
-    GenericRange::new_closed(start, end)
+fn parse_range(s: &str) -> Result<GenericRange<usize>, ParseError> {
+    let (left, right) = s
+        .split_once('-')
+        .ok_or_else(|| ParseError::at_end(s, "a dash"))?;
+    let start = token(s, left, "a start")?;
+    let end = token(s, right, "an end")?;
+    if end < start {
+        return Err(ParseError::at(s, right, "an end not below the start"));
+    }
+    Ok(GenericRange::new_closed(start, end))
 }
 
-fn parse_input(input: &str) -> (Ranges<usize>, Vec<usize>) {
-    let (ranges_str, ids_str) = input.split_once("\n\n").unwrap();
-    let ranges: Ranges<usize> = ranges_str
-        .split("\n")
-        .filter(|x| !x.is_empty())
-        .map(parse_range)
-        .collect();
-
-    let ids: Vec<usize> = ids_str
-        .split("\n")
-        .filter(|x| !x.is_empty())
-        .map(str::parse)
-        .map(Result::unwrap)
-        .collect();
-
-    (ranges, ids)
+fn parse_input(input: &str) -> Result<(Ranges<usize>, Vec<usize>), ParseError> {
+    let (ranges_str, ids_str) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(input, "an empty line"))?;
+    let ranges: Ranges<usize> = parse_lines(ranges_str, parse_range)?.into_iter().collect();
+
+    let ids = parse_lines(ids_str, |l| token(l, l, "an ingredient ID"))
+        .map_err(|e| e.within(input, ids_str))?;
+
+    Ok((ranges, ids))
 }
 
 #[cfg(test)]
@@ -87,7 +85,7 @@ mod tests {
     fn test_day5() {
         let input = String::from(TEST_INPUT);
 
-        let result = day5(&input);
+        let result = day5(&input).unwrap();
 
         assert_eq!(String::from("3"), result);
     }
@@ -97,8 +95,18 @@ mod tests {
         let _ = env_logger::builder().is_test(true).try_init();
         let input = String::from(TEST_INPUT);
 
-        let result = day5_2(&input);
+        let result = day5_2(&input).unwrap();
 
         assert_eq!(String::from("14"), result);
     }
+
+    #[test]
+    fn test_day5_reversed_range() {
+        let input = TEST_INPUT.replace("16-20", "20-16");
+
+        assert_eq!(
+            "line 3, column 4: expected an end not below the start",
+            day5(&input).unwrap_err().to_string()
+        );
+    }
 }
@@ -1,24 +1,28 @@
-use crate::aoc_2025::day6::Operator::{Add, Divide, Multiply, Subtract};
+use crate::answer::Answer;
+use crate::aoc_2025::day6::Operator::{Add, Multiply};
+use crate::common::parse::{token, ParseError};
+use crate::common::Grid;
+use anyhow::Result;
 #[cfg(not(test))]
 use log::info;
 #[cfg(test)]
 use std::println as info;
 use std::str::FromStr;
 
-pub fn day6(input: &String) -> String {
-    parse_input(input)
+pub fn day6(input: &str) -> Result<Answer> {
+    Ok(parse_input(input)?
         .iter()
         .map(|operation| operation.compute())
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
-pub fn day6_2(input: &String) -> String {
-    parse_input_2(input)
+pub fn day6_2(input: &str) -> Result<Answer> {
+    Ok(parse_input_2(input)?
         .iter()
         .map(|operation| operation.compute())
         .sum::<usize>()
-        .to_string()
+        .into())
 }
 
 #[derive(Debug)]
@@ -38,27 +42,21 @@ impl Operation {
 #[derive(Debug)]
 enum Operator {
     Add,
-    Subtract,
     Multiply,
-    Divide,
 }
 
 impl Operator {
     fn get_neutral(&self) -> usize {
         match self {
             Add => 0,
-            Subtract => 0,
             Multiply => 1,
-            Divide => 1,
         }
     }
 
     pub fn execute(&self, val1: &usize, val2: &usize) -> usize {
         match self {
             Add => val1 + val2,
-            Subtract => panic!("Not implemented"),
             Multiply => val1 * val2,
-            Divide => panic!("Not implemented"),
         }
     }
 
@@ -70,82 +68,99 @@ impl Operator {
 }
 
 impl FromStr for Operator {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input.trim() {
             "+" => Ok(Add),
-            "-" => Ok(Subtract),
             "*" => Ok(Multiply),
-            "/" => Ok(Divide),
-            _ => Err(()),
+            operator => Err(ParseError::at(input, operator, "+ or *")),
         }
     }
 }
 
-fn parse_input(input: &str) -> Vec<Operation> {
-    let split = parse_columns(input);
-    split.iter().map(|x| to_operation(x)).collect()
+fn parse_input(input: &str) -> Result<Vec<Operation>, ParseError> {
+    let split = parse_columns(input)?;
+    split.iter().map(|x| to_operation(input, x)).collect()
 }
 
-fn to_operation(col: &Vec<&str>) -> Operation {
-    let (operator_str, values_str) = col.split_last().unwrap();
+/// Columns are slices of the input, to locate errors in it
+fn to_operation(input: &str, col: &Vec<&str>) -> Result<Operation, ParseError> {
+    let (operator_str, values_str) = col.split_last().expect("columns are never empty");
     let values: Vec<usize> = values_str
         .iter()
-        .map(|x| x.trim().parse().unwrap())
-        .collect();
-    let operator = Operator::from_str(operator_str).unwrap();
-    Operation { values, operator }
+        .map(|x| token(input, x, "a number"))
+        .collect::<Result<_, _>>()?;
+    let operator = token(input, operator_str, "an operator")?;
+    Ok(Operation { values, operator })
 }
 
-fn parse_input_2(input: &str) -> Vec<Operation> {
-    let split = parse_columns(input);
-    split.iter().map(|x| to_operation_2(x)).collect()
+fn parse_input_2(input: &str) -> Result<Vec<Operation>, ParseError> {
+    let split = parse_columns(input)?;
+    split.iter().map(|x| to_operation_2(input, x)).collect()
 }
 
-fn to_operation_2(col: &Vec<&str>) -> Operation {
-    let (operator_str, values_str) = col.split_last().unwrap();
-
-    let col_len = values_str.first().unwrap().len();
-    let mut values: Vec<usize> = Vec::new();
-
-    for i in (0..col_len).rev() {
-        let value: String = values_str.iter().map(|x| x.get(i..=i).unwrap()).collect();
-        let result = value.trim().parse();
-        if result.is_ok() {
-            values.push(result.unwrap())
-        }
-    }
+/// Numbers are written top to bottom in columns, read from the right
+fn to_operation_2(input: &str, col: &Vec<&str>) -> Result<Operation, ParseError> {
+    let (operator_str, values_str) = col.split_last().expect("columns are never empty");
+
+    let col_len = values_str.first().map_or(0, |x| x.len());
+    let digits = Grid::new(
+        col_len,
+        values_str.len(),
+        values_str.iter().flat_map(|x| x.chars()).collect(),
+    );
+    let values: Vec<usize> = digits
+        .rotate_270()
+        .rows()
+        .filter_map(|row| row.iter().collect::<String>().trim().parse().ok())
+        .collect();
 
-    let operator = Operator::from_str(operator_str).unwrap();
+    let operator = token(input, operator_str, "an operator")?;
 
-    Operation { values, operator }
+    Ok(Operation { values, operator })
 }
 
-fn parse_columns(input: &str) -> Vec<Vec<&str>> {
+/// Split the worksheet on the columns made only of spaces, all lines having the same width
+fn parse_columns(input: &str) -> Result<Vec<Vec<&str>>, ParseError> {
     let split: Vec<&str> = input.split("\n").filter(|x| !x.is_empty()).collect();
-    let nb_of_columns = split.first().unwrap().len();
+    let Some(nb_of_columns) = split.first().map(|x| x.len()) else {
+        return Err(ParseError::at_end(input, "a line"));
+    };
+    for line in &split {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii()) {
+            return Err(ParseError::at(input, &line[i..i + c.len_utf8()], "a digit"));
+        }
+        if line.len() < nb_of_columns {
+            let expected = format!("{} characters", nb_of_columns);
+            return Err(ParseError::at(input, &line[line.len()..], &expected));
+        }
+        if line.len() > nb_of_columns {
+            return Err(ParseError::at(
+                input,
+                &line[nb_of_columns..],
+                "the end of the line",
+            ));
+        }
+    }
 
     let mut columns: Vec<Vec<&str>> = Vec::new();
     let mut last_index = 0;
     for i in 0..nb_of_columns {
-        let should_new_col = split.iter().all(|l| l.get(i..=i).unwrap().eq(" "));
+        let should_new_col = split.iter().all(|l| l[i..=i].eq(" "));
 
         if should_new_col {
-            let new_col = split
-                .iter()
-                .map(|l| l.get(last_index..i).unwrap())
-                .collect();
+            let new_col = split.iter().map(|l| &l[last_index..i]).collect();
             info!("New column parsed: {new_col:?}");
             columns.push(new_col);
             last_index = i;
         }
     }
     // push last column
-    let new_col = split.iter().map(|l| l.get(last_index..).unwrap()).collect();
+    let new_col = split.iter().map(|l| &l[last_index..]).collect();
     info!("New column parsed: {new_col:?}");
     columns.push(new_col);
-    columns
+    Ok(columns)
 }
 
 #[cfg(test)]
@@ -163,7 +178,7 @@ mod tests {
     fn test_day6() {
         let input = String::from(TEST_INPUT);
 
-        let result = day6(&input);
+        let result = day6(&input).unwrap();
 
         assert_eq!(String::from("4277556"), result);
     }
@@ -173,8 +188,28 @@ mod tests {
         let _ = env_logger::builder().is_test(true).try_init();
         let input = String::from(TEST_INPUT);
 
-        let result = day6_2(&input);
+        let result = day6_2(&input).unwrap();
 
         assert_eq!(String::from("3263827"), result);
     }
+
+    #[test]
+    fn test_day6_invalid_operator() {
+        let input = TEST_INPUT.replace("*   +   *", "*   +   x");
+
+        assert_eq!(
+            "line 7, column 9: expected an operator",
+            day6(&input).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_day6_unsupported_operator() {
+        let input = TEST_INPUT.replace("*   +   *", "*   +   -");
+
+        assert_eq!(
+            "line 7, column 9: expected an operator",
+            day6_2(&input).unwrap_err().to_string()
+        );
+    }
 }
@@ -1,164 +1,90 @@
-use crate::aoc_2024::common::Direction::{East, South, West};
-use crate::aoc_2024::common::{CharMatrix, Coordinates2D, Direction};
+use crate::answer::Answer;
+use crate::common::{Coordinates2D, Grid, ParseError, Vector};
+use anyhow::Result;
 #[cfg(not(test))]
 use log::info;
 use std::collections::HashSet;
 #[cfg(test)]
 use std::println as info;
-use std::str::FromStr;
-use std::time::Instant;
 
-pub fn day7(input: &String) -> String {
-    let map = CharMatrix::from_str(input).unwrap();
-    let map_bounds = map.get_bounds();
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Splitter,
+    Start,
+}
+
+/// Where a beam goes from a tile
+const BELOW: [Vector; 1] = [Vector::new(0, 1)];
+
+/// Where a beam goes when split
+const SIDES: [Vector; 2] = [Vector::new(-1, 0), Vector::new(1, 0)];
+
+fn parse_map(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, "'.', '^' or 'S'", |c| match c {
+        '.' => Some(Tile::Empty),
+        '^' => Some(Tile::Splitter),
+        'S' => Some(Tile::Start),
+        _ => None,
+    })
+}
 
-    let map_str = map.print();
-    info!("Map:\n{map_str}");
+pub fn day7(input: &str) -> Result<Answer> {
+    let map = parse_map(input)?;
+    info!("Map:\n{input}");
 
-    let mut beams: HashSet<Coordinates2D> = map.search_chars(&'S').into_iter().collect();
+    let mut beams: HashSet<Coordinates2D> = map.positions(|t| *t == Tile::Start).collect();
     let mut nb_of_split = 0;
     loop {
         beams = beams
             .iter()
-            .map(|b| b.advance(South))
-            .filter(|b| map_bounds.is_in_bounds(b))
-            .flat_map(|b| {
-                if map.get_char_at(&b).unwrap().eq(&'^') {
+            .flat_map(|b| map.stencil(b, &BELOW))
+            .flat_map(|(b, tile)| {
+                if *tile == Tile::Splitter {
                     // split
                     info!("Split happened!");
                     nb_of_split += 1;
-                    vec![b.advance(West), b.advance(East)]
+                    map.stencil(&b, &SIDES).map(|(side, _)| side).collect()
                 } else {
                     vec![b]
                 }
             })
-            .filter(|b| map_bounds.is_in_bounds(b))
             .collect();
         if beams.is_empty() {
             break;
         }
     }
 
-    nb_of_split.to_string()
-}
-
-#[derive(Debug)]
-struct Beam {
-    coordinates: Coordinates2D,
-    combi: usize,
-}
-
-impl Beam {
-    fn advance(&self, direction: Direction) -> Beam {
-        Beam {
-            coordinates: self.coordinates.advance(direction),
-            combi: self.combi,
-        }
-    }
-
-    fn fuse(&mut self, other: Beam) {
-        self.combi = self.combi + other.combi;
-    }
-
-    fn new(coordinates: Coordinates2D) -> Beam {
-        Beam {
-            coordinates,
-            combi: 1,
-        }
-    }
+    Ok(nb_of_split.into())
 }
 
-pub fn day7_2(input: &String) -> String {
-    let map = CharMatrix::from_str(input).unwrap();
-
-    let map_str = map.print();
-    info!("Map:\n{map_str}");
-
-    let mut beams: Vec<Beam> = map
-        .search_chars(&'S')
-        .into_iter()
-        .map(|x| Beam::new(x))
-        .collect();
+pub fn day7_2(input: &str) -> Result<Answer> {
+    let map = parse_map(input)?;
+    info!("Map:\n{input}");
 
+    // number of timelines in which a beam goes through each tile
+    let mut beams: Grid<usize> = map.map(|t| usize::from(*t == Tile::Start));
     let mut nb_of_split = 1;
-    let mut loop_nb = 0;
-    loop {
-        let start_time = Instant::now();
-        let (beams_advanced, splits) = advance_beams(&map, beams);
-        beams = beams_advanced;
-        nb_of_split += splits;
-
-        let mut matrix_clone = map.clone();
-        for beam in &beams {
-            matrix_clone.set_char('|', &beam.coordinates);
-        }
-
-        let duration_milli = start_time.elapsed().as_millis();
-
-        info!("beams {beams:?}");
-        info!(
-            "Iter {}, beams {} advanced:{}",
-            loop_nb,
-            beams.len(),
-            duration_milli
-        );
-
-        if beams.is_empty() {
-            break;
-        }
-        loop_nb += 1;
-        if loop_nb > 1000 {
-            break;
-        }
-    }
-
-    nb_of_split.to_string()
-}
-
-fn advance_beams(map: &CharMatrix, beams: Vec<Beam>) -> (Vec<Beam>, usize) {
-    let map_bounds = map.get_bounds();
-    let mut beams_advanced: Vec<Beam> = Vec::with_capacity(beams.len() * 2);
-    let mut splits = 0;
-
-    for beam in beams
-        .iter()
-        .map(|b| b.advance(South))
-        .filter(|b| map_bounds.is_in_bounds(&b.coordinates))
-    {
-        if map.get_char_at(&beam.coordinates).unwrap().eq(&'^') {
-            splits += beam.combi;
-            let west = beam.advance(West);
-            let east = beam.advance(East);
-            if map_bounds.is_in_bounds(&west.coordinates) {
-                let mut existing: Vec<&mut Beam> = beams_advanced
-                    .iter_mut()
-                    .filter(|x| x.coordinates == west.coordinates)
-                    .collect();
-                if existing.is_empty() {
-                    beams_advanced.push(west);
-                } else {
-                    let val = existing.first_mut().unwrap();
-                    val.fuse(west);
-                }
+    for y in 1..map.height() as isize {
+        for x in 0..map.width() as isize {
+            let above = beams[Coordinates2D::new(x, y - 1)];
+            if above == 0 {
+                continue;
             }
-            if map_bounds.is_in_bounds(&east.coordinates) {
-                let mut existing: Vec<&mut Beam> = beams_advanced
-                    .iter_mut()
-                    .filter(|x| x.coordinates == east.coordinates)
-                    .collect();
-                if existing.is_empty() {
-                    beams_advanced.push(east);
-                } else {
-                    let val = existing.first_mut().unwrap();
-                    val.fuse(east);
+            let tile = Coordinates2D::new(x, y);
+            if map[tile] == Tile::Splitter {
+                nb_of_split += above;
+                for (side, _) in map.stencil(&tile, &SIDES) {
+                    beams[side] += above;
                 }
+            } else {
+                beams[tile] += above;
             }
-        } else {
-            beams_advanced.push(beam);
         }
+        info!("Row {}, timelines: {:?}", y, beams.row(y as usize));
     }
 
-    (beams_advanced, splits)
+    Ok(nb_of_split.into())
 }
 
 #[cfg(test)]
@@ -187,7 +113,7 @@ mod tests {
     fn test_day7() {
         let input = String::from(TEST_INPUT);
 
-        let result = day7(&input);
+        let result = day7(&input).unwrap();
 
         assert_eq!(String::from("21"), result);
     }
@@ -197,7 +123,7 @@ mod tests {
         let _ = env_logger::builder().is_test(true).try_init();
         let input = String::from(TEST_INPUT);
 
-        let result = day7_2(&input);
+        let result = day7_2(&input).unwrap();
 
         assert_eq!(String::from("40"), result);
     }
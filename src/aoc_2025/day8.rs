@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::common::parse::{parse_lines, token, ParseError};
+use anyhow::{anyhow, Result};
+
 #[cfg(not(test))]
 use log::info;
 use std::collections::HashSet;
@@ -6,12 +10,8 @@ use std::println as info;
 use std::str::FromStr;
 // Solution
 
-pub fn day8(input: &String, nb_of_junctions: usize) -> String {
-    let points: Vec<Point> = input
-        .split("\n")
-        .filter(|s| !s.is_empty())
-        .map(|s| Point::from_str(s).unwrap())
-        .collect();
+pub fn day8(input: &str, nb_of_junctions: usize) -> Result<Answer> {
+    let points: Vec<Point> = parse_lines(input, Point::from_str)?;
 
     let links = build_links_sorted(points);
 
@@ -60,21 +60,17 @@ pub fn day8(input: &String, nb_of_junctions: usize) -> String {
 
     circuits.sort_by(|x1, x2| x1.len().cmp(&x2.len()));
 
-    circuits
+    Ok(circuits
         .iter()
         .rev()
         .take(3)
         .map(Circuit::len)
         .fold(1, |acc, x| acc * x)
-        .to_string()
+        .into())
 }
 
-pub fn day8_2(input: &String) -> String {
-    let points: Vec<Point> = input
-        .split("\n")
-        .filter(|s| !s.is_empty())
-        .map(|s| Point::from_str(s).unwrap())
-        .collect();
+pub fn day8_2(input: &str) -> Result<Answer> {
+    let points: Vec<Point> = parse_lines(input, Point::from_str)?;
 
     let nb_of_points = points.len();
     info!("parsed {nb_of_points} points");
@@ -127,8 +123,9 @@ pub fn day8_2(input: &String) -> String {
         println!("Circuit: {c:?}: {length}")
     });
 
-    let last_link = last_link_opt.unwrap();
-    (last_link.from.x * last_link.to.x).to_string()
+    let last_link =
+        last_link_opt.ok_or_else(|| anyhow!("At least two junction boxes are needed"))?;
+    Ok((last_link.from.x * last_link.to.x).into())
 }
 
 fn single_containing_all_circuit(nb_of_points: usize, circuits: &mut Vec<Circuit>) -> bool {
@@ -184,13 +181,19 @@ struct Point {
 }
 
 impl FromStr for Point {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut split = s.split(",");
-        let x: isize = split.next().unwrap().parse().unwrap();
-        let y: isize = split.next().unwrap().parse().unwrap();
-        let z: isize = split.next().unwrap().parse().unwrap();
+        let mut coordinate = |name: &str| {
+            let value = split
+                .next()
+                .ok_or_else(|| ParseError::at_end(s, &format!("a {} coordinate", name)))?;
+            token(s, value, &format!("a {} coordinate", name))
+        };
+        let x: isize = coordinate("x")?;
+        let y: isize = coordinate("y")?;
+        let z: isize = coordinate("z")?;
         Ok(Point { x, y, z })
     }
 }
@@ -337,7 +340,7 @@ mod tests {
     fn test_day8() {
         let input = String::from(TEST_INPUT);
 
-        let result = day8(&input, 10);
+        let result = day8(&input, 10).unwrap();
 
         assert_eq!(String::from("40"), result);
     }
@@ -346,7 +349,7 @@ mod tests {
     fn test_day8_2() {
         let input = String::from(TEST_INPUT);
 
-        let result = day8_2(&input);
+        let result = day8_2(&input).unwrap();
 
         assert_eq!(String::from("25272"), result);
     }
@@ -1,4 +1,7 @@
-use crate::aoc_2024::common::{CharMatrix, Coordinates2D, Rectangle, Segment};
+use crate::answer::Answer;
+use crate::common::parse::parse_lines;
+use crate::common::{CharMatrix, Coordinates2D, Rectangle, Segment};
+use anyhow::{anyhow, Result};
 #[cfg(not(test))]
 use log::info;
 #[cfg(test)]
@@ -6,28 +9,22 @@ use std::println as info;
 use std::str::FromStr;
 // Solution
 
-pub fn day9(input: &String) -> String {
-    let tiles: Vec<Coordinates2D> = input
-        .split("\n")
-        .map(Coordinates2D::from_str)
-        .filter(Result::is_ok)
-        .map(Result::unwrap)
-        .collect();
+pub fn day9(input: &str) -> Result<Answer> {
+    let tiles: Vec<Coordinates2D> = parse_lines(input, Coordinates2D::from_str)?;
 
     print_as_matrix(&tiles);
 
     let rect = build_rect_from_red_tiles(&tiles);
 
-    rect.iter().map(Rectangle::area).max().unwrap().to_string()
+    rect.iter()
+        .map(Rectangle::area)
+        .max()
+        .map(Answer::from)
+        .ok_or_else(|| anyhow!("No rectangle between red tiles"))
 }
 
-pub fn day9_2(input: &String) -> String {
-    let tiles: Vec<Coordinates2D> = input
-        .split("\n")
-        .map(Coordinates2D::from_str)
-        .filter(Result::is_ok)
-        .map(Result::unwrap)
-        .collect();
+pub fn day9_2(input: &str) -> Result<Answer> {
+    let tiles: Vec<Coordinates2D> = parse_lines(input, Coordinates2D::from_str)?;
 
     print_as_matrix(&tiles);
 
@@ -47,8 +44,8 @@ pub fn day9_2(input: &String) -> String {
         .iter()
         .map(Rectangle::area)
         .max()
-        .unwrap()
-        .to_string()
+        .map(Answer::from)
+        .ok_or_else(|| anyhow!("No rectangle inside the red tiles path"))
 }
 
 fn print_as_matrix(tiles: &Vec<Coordinates2D>) {
@@ -92,7 +89,7 @@ mod tests {
     fn test_day8() {
         let input = String::from(TEST_INPUT);
 
-        let result = day9(&input);
+        let result = day9(&input).unwrap();
 
         assert_eq!(String::from("50"), result);
     }
@@ -101,7 +98,7 @@ mod tests {
     fn test_day8_2() {
         let input = String::from(TEST_INPUT);
 
-        let result = day9_2(&input);
+        let result = day9_2(&input).unwrap();
 
         assert_eq!(String::from("24"), result);
     }
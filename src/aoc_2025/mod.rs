@@ -1,11 +1,60 @@
-mod common;
-pub(super) mod day1;
-pub(super) mod day2;
-pub(super) mod day3;
-pub(super) mod day4;
-pub(super) mod day5;
-pub(super) mod day6;
-pub(super) mod day7;
-pub(super) mod day8;
-pub(super) mod day9;
-// pub(super) mod day11;
+use crate::solver::{Puzzle, Registry};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+// pub mod day11;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(
+        Puzzle::new(2025, 1, "Secret Entrance")
+            .with_part1(day1::day1)
+            .with_part2(day1::day1_2),
+    );
+    registry.register(
+        Puzzle::new(2025, 2, "Gift Shop")
+            .with_part1(day2::day2)
+            .with_part2(day2::day2_2),
+    );
+    registry.register(
+        Puzzle::new(2025, 3, "Lobby")
+            .with_part1(day3::day3)
+            .with_part2(day3::day3_2),
+    );
+    registry.register(
+        Puzzle::new(2025, 4, "Printing Department")
+            .with_part1(day4::day4)
+            .with_part2(day4::day4_2),
+    );
+    registry.register(
+        Puzzle::new(2025, 5, "Cafeteria")
+            .with_part1(day5::day5)
+            .with_part2(day5::day5_2),
+    );
+    registry.register(
+        Puzzle::new(2025, 6, "Trash Compactor")
+            .with_part1(day6::day6)
+            .with_part2(day6::day6_2),
+    );
+    registry.register(
+        Puzzle::new(2025, 7, "Laboratories")
+            .with_part1(day7::day7)
+            .with_part2(day7::day7_2),
+    );
+    registry.register(
+        Puzzle::new(2025, 8, "Playground")
+            .with_part1(|input| day8::day8(input, 1000))
+            .with_part2(day8::day8_2),
+    );
+    registry.register(
+        Puzzle::new(2025, 9, "Movie Theater")
+            .with_part1(day9::day9)
+            .with_part2(day9::day9_2),
+    );
+}
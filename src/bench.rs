@@ -0,0 +1,211 @@
+// Repeated timing of a solver part, to compare optimisations
+
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+
+use crate::cache::{read_if_exists, write_creating_dirs, InputCache};
+use crate::report::format_duration;
+use crate::solver::{Part, Solver};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let count = sorted.len();
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count as f64;
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+        Stats {
+            iterations: count,
+            mean: Duration::from_nanos(mean.round() as u64),
+            median,
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            min: sorted[0],
+        }
+    }
+
+    /// Saved as `key=nanoseconds` lines
+    pub fn to_baseline(&self) -> String {
+        format!(
+            "iterations={}\nmean={}\nmedian={}\nstddev={}\nmin={}\n",
+            self.iterations,
+            self.mean.as_nanos(),
+            self.median.as_nanos(),
+            self.stddev.as_nanos(),
+            self.min.as_nanos()
+        )
+    }
+
+    pub fn from_baseline(baseline: &str) -> Option<Stats> {
+        let value = |key: &str| -> Option<u64> {
+            baseline
+                .lines()
+                .filter_map(|l| l.split_once('='))
+                .find(|(k, _)| *k == key)
+                .and_then(|(_, v)| v.trim().parse().ok())
+        };
+        Some(Stats {
+            iterations: value("iterations")? as usize,
+            mean: Duration::from_nanos(value("mean")?),
+            median: Duration::from_nanos(value("median")?),
+            stddev: Duration::from_nanos(value("stddev")?),
+            min: Duration::from_nanos(value("min")?),
+        })
+    }
+
+    /// Description of the changes from the baseline, as percentages
+    pub fn compare(&self, baseline: &Stats) -> String {
+        format!(
+            "mean {}, median {}, min {}",
+            change(baseline.mean, self.mean),
+            change(baseline.median, self.median),
+            change(baseline.min, self.min)
+        )
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "iterations {}", self.iterations)?;
+        writeln!(f, "mean       {}", format_duration(&self.mean))?;
+        writeln!(f, "median     {}", format_duration(&self.median))?;
+        writeln!(f, "stddev     {}", format_duration(&self.stddev))?;
+        writeln!(f, "min        {}", format_duration(&self.min))
+    }
+}
+
+fn change(before: Duration, after: Duration) -> String {
+    let before = before.as_secs_f64();
+    if before == 0.0 {
+        return String::from("n/a");
+    }
+    format!("{:+.1}%", (after.as_secs_f64() - before) / before * 100.0)
+}
+
+/// Runs `function` `warmup` times without measuring, then measures `iterations` runs
+pub fn bench<T>(warmup: usize, iterations: usize, function: impl Fn() -> T) -> Stats {
+    for _ in 0..warmup {
+        std::hint::black_box(function());
+    }
+    let samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let now = Instant::now();
+            std::hint::black_box(function());
+            now.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+/// Times a part of the solver, after checking it finds an answer
+pub fn bench_part(
+    solver: &dyn Solver,
+    part: Part,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<Stats> {
+    solver.answer(part, input)?;
+    Ok(bench(warmup, iterations, || solver.solve(part, input)))
+}
+
+/// Baselines are kept with the cached input, as `<dir>/2024/day09.part1.<name>.bench`
+pub fn baseline_path(cache: &InputCache, year: u16, day: u8, part: Part, name: &str) -> PathBuf {
+    cache.day_file(year, day, &format!("part{}.{}.bench", part, name))
+}
+
+pub fn load_baseline(path: &Path) -> Result<Stats> {
+    read_if_exists(path)?
+        .and_then(|content| Stats::from_baseline(&content))
+        .ok_or_else(|| anyhow!("No baseline in {}", path.display()))
+}
+
+pub fn save_baseline(path: &Path, stats: &Stats) -> Result<()> {
+    write_creating_dirs(path, &stats.to_baseline())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&millis(&[4, 2, 4, 4, 5, 5, 7, 9]));
+
+        assert_eq!(8, stats.iterations);
+        assert_eq!(Duration::from_millis(5), stats.mean);
+        assert_eq!(Duration::from_micros(4500), stats.median);
+        assert_eq!(Duration::from_millis(2), stats.stddev);
+        assert_eq!(Duration::from_millis(2), stats.min);
+    }
+
+    #[test]
+    fn test_stats_odd_median() {
+        let stats = Stats::from_samples(&millis(&[3, 1, 2]));
+
+        assert_eq!(Duration::from_millis(2), stats.median);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let stats = Stats::from_samples(&millis(&[1, 2, 3, 4]));
+
+        assert_eq!(
+            Some(stats.clone()),
+            Stats::from_baseline(&stats.to_baseline())
+        );
+        assert_eq!(None, Stats::from_baseline("mean=12"));
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Stats::from_samples(&millis(&[10, 10]));
+        let faster = Stats::from_samples(&millis(&[8, 9]));
+
+        assert_eq!(
+            "mean -15.0%, median -15.0%, min -20.0%",
+            faster.compare(&baseline)
+        );
+    }
+
+    #[test]
+    fn test_bench_runs() {
+        let stats = bench(2, 5, || (0..1000).sum::<usize>());
+
+        assert_eq!(5, stats.iterations);
+        assert!(stats.min <= stats.median);
+    }
+
+    #[test]
+    fn test_baseline_path() {
+        let cache = InputCache::new("inputs");
+
+        assert_eq!(
+            PathBuf::from("inputs")
+                .join("2024")
+                .join("day09.part2.before.bench"),
+            baseline_path(&cache, 2024, 9, Part::Two, "before")
+        );
+    }
+}
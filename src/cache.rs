@@ -0,0 +1,138 @@
+// Keep downloaded inputs on disk so they are fetched only once
+
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::client::AocClient;
+
+#[derive(Debug, Clone)]
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> InputCache {
+        InputCache { dir: dir.into() }
+    }
+
+    /// Location of an input, as `<dir>/2024/day09.txt`
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_file(year, day, "txt")
+    }
+
+    /// Location of another file kept for a day, as `<dir>/2024/day09.<extension>`
+    pub fn day_file(&self, year: u16, day: u8, extension: &str) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.{}", day, extension))
+    }
+
+    /// Returns `None` when the input has not been stored yet
+    pub fn read(&self, year: u16, day: u8) -> Result<Option<String>> {
+        read_if_exists(&self.path(year, day))
+    }
+
+    pub fn write(&self, year: u16, day: u8, input: &str) -> Result<()> {
+        write_creating_dirs(&self.path(year, day), input)
+    }
+
+    /// Reads the input, downloading and storing it only when missing. The client is only created
+    /// to download, which is refused when offline
+    pub async fn get_or_download(
+        &self,
+        year: u16,
+        day: u8,
+        offline: bool,
+        client: impl FnOnce() -> Result<AocClient>,
+    ) -> Result<String> {
+        if let Some(input) = self.read(year, day)? {
+            log::info!("Using cached input {}", self.path(year, day).display());
+            return Ok(input);
+        }
+        if offline {
+            return Err(anyhow!(
+                "Input of {} day {} is not cached in {} and cannot be downloaded in offline mode",
+                year,
+                day,
+                self.path(year, day).display()
+            ));
+        }
+        let input = client()?.download_input(year, day).await?;
+        self.write(year, day, &input)?;
+        Ok(input)
+    }
+}
+
+/// Returns `None` when the file does not exist
+pub fn read_if_exists(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error).with_context(|| format!("Cannot read {}", path.display())),
+    }
+}
+
+/// Writes the file, creating its parent directories if needed
+pub fn write_creating_dirs(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Cannot create directory {}", parent.display()))?;
+    }
+    fs::write(path, content).with_context(|| format!("Cannot write {}", path.display()))
+}
+
+/// Adds to the end of the file, creating it and its parent directories if needed
+pub fn append_creating_dirs(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Cannot create directory {}", parent.display()))?;
+    }
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .with_context(|| format!("Cannot write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The cache is in a directory deleted with the returned one
+    fn test_cache() -> (tempfile::TempDir, InputCache) {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        (dir, cache)
+    }
+
+    #[test]
+    fn test_path() {
+        let cache = InputCache::new("inputs");
+
+        assert_eq!(
+            PathBuf::from("inputs").join("2024").join("day09.txt"),
+            cache.path(2024, 9)
+        );
+    }
+
+    #[test]
+    fn test_read_missing() {
+        let (_dir, cache) = test_cache();
+
+        assert_eq!(None, cache.read(2024, 1).unwrap());
+    }
+
+    #[test]
+    fn test_write_then_read() {
+        let (_dir, cache) = test_cache();
+
+        cache.write(2025, 12, "1 2 3\n").unwrap();
+
+        assert_eq!(Some(String::from("1 2 3\n")), cache.read(2025, 12).unwrap());
+        assert_eq!(None, cache.read(2025, 11).unwrap());
+    }
+}
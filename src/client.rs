@@ -0,0 +1,510 @@
+// HTTP access to the Advent of Code website
+
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use reqwest::header::{HeaderValue, COOKIE};
+use reqwest::{Client, ClientBuilder, Method, RequestBuilder, StatusCode};
+
+use crate::cache::{append_creating_dirs, read_if_exists};
+use crate::session::Session;
+use crate::solver::Part;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Why the input endpoint did not give an input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    SessionExpired,
+    /// Puzzles unlock at midnight EST, `unlocks_in` is zero when the server disagrees with the clock
+    NotUnlocked {
+        year: u16,
+        day: u8,
+        unlocks_in: Duration,
+    },
+    Unexpected {
+        status: u16,
+        body: String,
+    },
+}
+
+impl InputError {
+    /// Checks the response of the input endpoint, whose body is the input only when this is `None`
+    pub fn classify(year: u16, day: u8, status: u16, body: &str) -> Option<InputError> {
+        if body.contains("Puzzle inputs differ by user") || status == 400 {
+            Some(InputError::SessionExpired)
+        } else if body.contains("before it unlocks") {
+            Some(InputError::NotUnlocked {
+                year,
+                day,
+                unlocks_in: unlocks_in(year, day, SystemTime::now()),
+            })
+        } else if status != 200 || body.is_empty() {
+            Some(InputError::Unexpected {
+                status,
+                body: body.lines().next().unwrap_or("").to_string(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::SessionExpired => write!(
+                f,
+                "The session expired or is invalid, log in on the website to get a new session cookie"
+            ),
+            InputError::NotUnlocked {
+                year,
+                day,
+                unlocks_in,
+            } if !unlocks_in.is_zero() => write!(
+                f,
+                "{} day {} unlocks in {}",
+                year,
+                day,
+                format_countdown(unlocks_in)
+            ),
+            InputError::NotUnlocked { year, day, .. } => {
+                write!(f, "{} day {} is not unlocked yet", year, day)
+            }
+            InputError::Unexpected { status, body } => {
+                write!(f, "Unexpected response {} from the website: {}", status, body)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Midnight EST, 5:00 UTC, on the day of December
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let days = days_from_civil(year as i64, 12, day as i64);
+    UNIX_EPOCH + Duration::from_secs((days * 86400 + 5 * 3600) as u64)
+}
+
+fn unlocks_in(year: u16, day: u8, now: SystemTime) -> Duration {
+    unlock_time(year, day)
+        .duration_since(now)
+        .unwrap_or(Duration::ZERO)
+}
+
+/// Days since 1970-01-01 of a date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn wait_before_next(last: Option<SystemTime>, now: SystemTime, min_interval: Duration) -> Duration {
+    last.and_then(|last| now.duration_since(last).ok())
+        .map_or(Duration::ZERO, |elapsed| {
+            min_interval.saturating_sub(elapsed)
+        })
+}
+
+fn backoff(delay: Duration, attempt: u32) -> Duration {
+    delay * 2u32.pow(attempt)
+}
+
+/// Requests sent, one per line as `<unix time in ms>\t<method>\t<path>\t<status or error>`
+struct RequestLog {
+    path: PathBuf,
+}
+
+impl RequestLog {
+    fn last_request(&self) -> Result<Option<SystemTime>> {
+        Ok(read_if_exists(&self.path)?.and_then(|log| {
+            let millis: u64 = log.lines().last()?.split('\t').next()?.parse().ok()?;
+            Some(UNIX_EPOCH + Duration::from_millis(millis))
+        }))
+    }
+
+    fn append(&self, sent_at: SystemTime, method: &str, path: &str, outcome: &str) -> Result<()> {
+        let millis = sent_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        append_creating_dirs(
+            &self.path,
+            &format!(
+                "{}\t{}\t{}\t{}\n",
+                millis,
+                method,
+                path,
+                outcome.replace(['\t', '\n'], " ")
+            ),
+        )
+    }
+}
+
+/// Like "2d 3h 12m", or "45s" under a minute
+fn format_countdown(duration: &Duration) -> String {
+    let seconds = duration.as_secs();
+    let (days, hours, minutes) = (seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60);
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{}s", seconds),
+        (0, 0, _) => format!("{}m", minutes),
+        (0, _, _) => format!("{}h {}m", hours, minutes),
+        _ => format!("{}d {}h {}m", days, hours, minutes),
+    }
+}
+
+/// Identifies the tool to the website, as its automation guidelines ask
+pub const USER_AGENT: &str = concat!(
+    "github.com/hmdebenque/advent-of-code-rs/",
+    env!("CARGO_PKG_VERSION")
+);
+
+pub const RETRIES: u32 = 3;
+
+/// Wait before the first new attempt, doubled for each of the next ones
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+pub struct AocClient {
+    base_url: String,
+    /// Session cookie, marked sensitive so it is not in the logged requests
+    cookie: HeaderValue,
+    http: Client,
+    min_interval: Duration,
+    /// Start of the last request sent by this client
+    last_sent: Mutex<Option<SystemTime>>,
+    retries: u32,
+    request_log: Option<RequestLog>,
+}
+
+impl AocClient {
+    /// `base_url` is the website root, like `https://adventofcode.com`
+    pub fn new(base_url: &str, session: &Session) -> Result<AocClient> {
+        let mut cookie = HeaderValue::from_str(&format!("session={}", session.token()))?;
+        cookie.set_sensitive(true);
+        Ok(AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            cookie,
+            http: ClientBuilder::new()
+                .timeout(TIMEOUT)
+                .user_agent(USER_AGENT)
+                .build()?,
+            min_interval: Duration::ZERO,
+            last_sent: Mutex::new(None),
+            retries: RETRIES,
+            request_log: None,
+        })
+    }
+
+    /// Minimum time between the start of two requests of the client, also across runs when a log
+    /// is kept
+    pub fn with_min_interval(mut self, min_interval: Duration) -> AocClient {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Number of new attempts of a GET after a transient failure, like a timeout or a server error.
+    /// Other requests like answer submissions are never sent twice, the server may have handled them
+    pub fn with_retries(mut self, retries: u32) -> AocClient {
+        self.retries = retries;
+        self
+    }
+
+    /// Keeps a line for every request sent in this file
+    pub fn with_request_log(mut self, path: PathBuf) -> AocClient {
+        self.request_log = Some(RequestLog { path });
+        self
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path.trim_start_matches('/'))
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http
+            .request(method, self.url(path))
+            .header(COOKIE, self.cookie.clone())
+    }
+
+    /// Sends the request, waiting for the minimum interval and retrying transient failures of GETs
+    async fn send(&self, request: RequestBuilder) -> Result<(StatusCode, String)> {
+        let mut attempt = 0;
+        loop {
+            let retry = request
+                .try_clone()
+                .ok_or_else(|| anyhow!("Cannot send request {:?}", request))?;
+            let (method, path) = match retry.try_clone().map(RequestBuilder::build) {
+                Some(Ok(built)) => (built.method().clone(), built.url().path().to_string()),
+                _ => (Method::default(), String::new()),
+            };
+            self.wait_turn().await?;
+
+            log::info!("Sending request {:?}", retry);
+            let sent_at = SystemTime::now();
+            *self.last_sent.lock().unwrap() = Some(sent_at);
+            let response = match retry.send().await {
+                Ok(response) => {
+                    let status = response.status();
+                    response.text().await.map(|body| (status, body))
+                }
+                Err(error) => Err(error),
+            };
+            let outcome = match &response {
+                Ok((status, _)) => status.as_u16().to_string(),
+                Err(error) => error.to_string(),
+            };
+            if let Some(log) = &self.request_log {
+                log.append(sent_at, method.as_str(), &path, &outcome)?;
+            }
+
+            let transient = match &response {
+                Ok((status, _)) => {
+                    status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS
+                }
+                Err(error) => error.is_timeout() || error.is_connect(),
+            };
+            if transient && method == Method::GET && attempt < self.retries {
+                let delay = backoff(RETRY_DELAY, attempt);
+                log::warn!(
+                    "Request {} {} failed with {}, retrying in {:?}",
+                    method,
+                    path,
+                    outcome,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            let (status, body) = response?;
+            log::info!("response {}: {}", status, body);
+            return Ok((status, body));
+        }
+    }
+
+    /// Sleeps until the minimum interval since the last request, sent or logged, is over
+    async fn wait_turn(&self) -> Result<()> {
+        let logged = match &self.request_log {
+            Some(log) => log.last_request()?,
+            None => None,
+        };
+        let last = logged.max(*self.last_sent.lock().unwrap());
+        let wait = wait_before_next(last, SystemTime::now(), self.min_interval);
+        if !wait.is_zero() {
+            log::info!("Waiting {:?} before the next request", wait);
+            tokio::time::sleep(wait).await;
+        }
+        Ok(())
+    }
+
+    /// Fails with an `InputError` instead of returning anything else than an input
+    pub async fn download_input(&self, year: u16, day: u8) -> Result<String> {
+        let unlocks_in = unlocks_in(year, day, SystemTime::now());
+        if !unlocks_in.is_zero() {
+            return Err(InputError::NotUnlocked {
+                year,
+                day,
+                unlocks_in,
+            }
+            .into());
+        }
+        let request = self.request(Method::GET, &format!("{}/day/{}/input", year, day));
+        let (status, body) = self.send(request).await?;
+        match InputError::classify(year, day, status.as_u16(), &body) {
+            Some(error) => Err(error.into()),
+            None => Ok(body),
+        }
+    }
+
+    /// HTML page of the puzzle description, with part two once part one is solved
+    pub async fn download_puzzle(&self, year: u16, day: u8) -> Result<String> {
+        let request = self.request(Method::GET, &format!("{}/day/{}", year, day));
+        let (_, body) = self.send(request).await?;
+        Ok(body)
+    }
+
+    /// JSON of a private leaderboard, which the website asks not to fetch more than every 15 minutes
+    pub async fn download_leaderboard(&self, year: u16, id: u64) -> Result<String> {
+        let request = self.request(
+            Method::GET,
+            &format!("{}/leaderboard/private/view/{}.json", year, id),
+        );
+        let (status, body) = self.send(request).await?;
+        if status != StatusCode::OK {
+            return Err(anyhow!(
+                "Cannot get leaderboard {} of {}: {}",
+                id,
+                year,
+                status
+            ));
+        }
+        Ok(body)
+    }
+
+    /// Posts an answer, returning the HTML page of the response
+    pub async fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<String> {
+        let request = self
+            .request(Method::POST, &format!("{}/day/{}/answer", year, day))
+            .form(&[("level", part.to_string().as_str()), ("answer", answer)]);
+        let (_, body) = self.send(request).await?;
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url() {
+        let client = AocClient::new("http://localhost:8080/", &Session::new("session")).unwrap();
+
+        assert_eq!(
+            "http://localhost:8080/2024/day/1/input",
+            client.url("/2024/day/1/input")
+        );
+    }
+
+    #[test]
+    fn test_logged_request_hides_session() {
+        let client = AocClient::new(DEFAULT_BASE_URL, &Session::new("53616c7465645f5f")).unwrap();
+
+        let request = format!("{:?}", client.request(Method::GET, "2024/day/1/input"));
+
+        assert!(!request.contains("53616c7465645f5f"), "{}", request);
+    }
+
+    #[test]
+    fn test_classify_input() {
+        assert_eq!(None, InputError::classify(2024, 1, 200, "3   4\n"));
+        assert_eq!(
+            Some(InputError::SessionExpired),
+            InputError::classify(
+                2024,
+                1,
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+            )
+        );
+        assert_eq!(
+            Some(InputError::NotUnlocked {
+                year: 2024,
+                day: 1,
+                unlocks_in: Duration::ZERO
+            }),
+            InputError::classify(
+                2024,
+                1,
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time."
+            )
+        );
+        assert_eq!(
+            Some(InputError::Unexpected {
+                status: 500,
+                body: String::from("Internal Server Error")
+            }),
+            InputError::classify(2024, 1, 500, "Internal Server Error\nretry")
+        );
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2024-12-01T05:00:00Z
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_secs(1733029200),
+            unlock_time(2024, 1)
+        );
+        let before = unlock_time(2025, 12) - Duration::from_secs(3 * 3600 + 12 * 60 + 30);
+        assert_eq!(
+            Duration::from_secs(3 * 3600 + 12 * 60 + 30),
+            unlocks_in(2025, 12, before)
+        );
+        assert_eq!(Duration::ZERO, unlocks_in(2024, 1, SystemTime::now()));
+    }
+
+    #[test]
+    fn test_not_unlocked_message() {
+        let error = InputError::NotUnlocked {
+            year: 2025,
+            day: 12,
+            unlocks_in: Duration::from_secs(3 * 3600 + 12 * 60 + 30),
+        };
+
+        assert_eq!("2025 day 12 unlocks in 3h 12m", error.to_string());
+        assert_eq!("45s", format_countdown(&Duration::from_secs(45)));
+        assert_eq!(
+            "2d 0h 5m",
+            format_countdown(&Duration::from_secs(2 * 86400 + 300))
+        );
+    }
+
+    #[test]
+    fn test_wait_before_next() {
+        let now = SystemTime::now();
+        let interval = Duration::from_secs(3);
+
+        assert_eq!(Duration::ZERO, wait_before_next(None, now, interval));
+        assert_eq!(
+            Duration::from_secs(2),
+            wait_before_next(Some(now - Duration::from_secs(1)), now, interval)
+        );
+        assert_eq!(
+            Duration::ZERO,
+            wait_before_next(Some(now - Duration::from_secs(5)), now, interval)
+        );
+    }
+
+    #[test]
+    fn test_backoff() {
+        let delay = Duration::from_millis(500);
+
+        assert_eq!(Duration::from_millis(500), backoff(delay, 0));
+        assert_eq!(Duration::from_secs(2), backoff(delay, 2));
+    }
+
+    #[test]
+    fn test_request_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("requests.log");
+        let log = RequestLog { path: path.clone() };
+        let sent_at = UNIX_EPOCH + Duration::from_millis(1733029200123);
+
+        assert_eq!(None, log.last_request().unwrap());
+        log.append(UNIX_EPOCH, "GET", "/2024/day/1/input", "503")
+            .unwrap();
+        log.append(sent_at, "GET", "/2024/day/1/input", "200")
+            .unwrap();
+
+        assert_eq!(Some(sent_at), log.last_request().unwrap());
+        assert_eq!(
+            "0\tGET\t/2024/day/1/input\t503\n1733029200123\tGET\t/2024/day/1/input\t200\n",
+            std::fs::read_to_string(path).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_default_url() {
+        let client = AocClient::new(DEFAULT_BASE_URL, &Session::new("session")).unwrap();
+
+        assert_eq!(
+            "https://adventofcode.com/2025/day/3",
+            client.url("2025/day/3")
+        );
+    }
+}
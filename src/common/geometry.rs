@@ -1,12 +1,13 @@
-// Regroup code used in several puzzles
+// Points, vectors, segments and rectangles of the plane, and the directions to move in it
 
-use crate::aoc_2024::common::Direction::{
+use crate::common::geometry::Direction::{
     East, North, NorthEast, NorthWest, South, SouthEast, SouthWest, West,
 };
+use crate::common::parse::{token, ParseError};
 use std::str::FromStr;
 use strum_macros::EnumIter;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Coordinates2D {
     pub x: isize,
     pub y: isize,
@@ -18,34 +19,27 @@ impl Coordinates2D {
     }
 
     pub fn advance(&self, direction: Direction) -> Coordinates2D {
-        direction.advance(&self)
+        direction.advance(self)
+    }
+
+    /// Number of orthogonal moves to reach the other coordinates
+    pub fn manhattan_distance(&self, other: &Coordinates2D) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
     }
 }
 
 impl FromStr for Coordinates2D {
-    type Err = ();
+    type Err = ParseError;
 
+    /// Parses "x,y"
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split(",");
-        let x_opt = split
-            .next()
-            .map(str::parse)
-            .filter(Result::is_ok)
-            .map(Result::unwrap);
-        if x_opt.is_none() {
-            return Err(());
-        }
-        let x: isize = x_opt.unwrap();
-        let y_opt = split
-            .next()
-            .map(str::parse)
-            .filter(Result::is_ok)
-            .map(Result::unwrap);
-        if y_opt.is_none() {
-            return Err(());
-        }
-        let y: isize = y_opt.unwrap();
-        Ok(Coordinates2D { x, y })
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::at_end(s, "a comma"))?;
+        Ok(Coordinates2D {
+            x: token(s, x, "an abscissa")?,
+            y: token(s, y, "an ordinate")?,
+        })
     }
 }
 
@@ -118,7 +112,7 @@ pub struct Vector {
 }
 
 impl Vector {
-    pub fn new(x: isize, y: isize) -> Vector {
+    pub const fn new(x: isize, y: isize) -> Vector {
         Vector { x, y }
     }
 
@@ -235,207 +229,23 @@ impl Direction {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct CharMatrix {
-    matrix: Vec<Vec<char>>,
-}
-
-impl CharMatrix {
-    pub fn copy_filled(&self, replacement: char) -> CharMatrix {
-        let mut new_matrix = CharMatrix::new();
-
-        self.matrix
-            .iter()
-            .map(|row| row.iter().map(|_| replacement).collect())
-            .for_each(|row| new_matrix.insert_row(row));
-        new_matrix
-    }
-
-    pub fn print(&self) -> String {
-        self.matrix
-            .iter()
-            .map(|row| row.iter().collect::<String>())
-            .map(|row| format!("{}\n", row))
-            .collect()
-    }
-}
-
-impl FromStr for CharMatrix {
-    type Err = ();
-
-    // parse lines to create a matrix of chars
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        Ok(input
-            .split("\n")
-            .filter(|l| !l.is_empty())
-            .fold(CharMatrix::new(), CharMatrix::parse_and_insert_line))
-    }
-}
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub struct CharMatrixElement {
-    pub coordinates: Coordinates2D,
-    pub value: char,
-}
-
-impl CharMatrix {
-    pub fn new() -> Self {
-        CharMatrix { matrix: Vec::new() }
-    }
-
-    pub fn insert_row(&mut self, row: Vec<char>) {
-        self.matrix.push(row);
-    }
-
-    pub fn set_char(&mut self, value: char, location: &Coordinates2D) {
-        self.ensure_y(1 + location.y as usize);
-        self.ensure_x(1 + location.x as usize);
-        self.matrix[location.y as usize][location.x as usize] = value;
-    }
-
-    pub fn ensure_y(&mut self, height: usize) {
-        if self.matrix.len() < height {
-            for _ in self.matrix.len()..height {
-                self.matrix.push(Vec::new());
-            }
-        }
-    }
-
-    pub fn ensure_x(&mut self, width: usize) {
-        for row in self.matrix.iter_mut() {
-            if row.len() < width {
-                for _ in row.len()..width {
-                    row.push('.');
-                }
-            }
-        }
-    }
-
-    fn parse_and_insert_line(mut self, line: &str) -> Self {
-        self.insert_row(line.chars().collect());
-        self
-    }
-
-    /// get coordinates of the first char matching search
-    pub fn search_char(&self, search: &char) -> Option<Coordinates2D> {
-        for ordinate in 1..(self.matrix.len() - 1) {
-            for abscissa in 1..(self.matrix[ordinate].len() - 1) {
-                let char = self.matrix[ordinate][abscissa];
-                if search.eq(&char) {
-                    return Some(Coordinates2D::new(abscissa as isize, ordinate as isize));
-                }
-            }
-        }
-        None
-    }
-
-    pub fn search_chars(&self, search: &char) -> Vec<Coordinates2D> {
-        self.get_all_chars()
-            .iter()
-            .filter(|c| search.eq(&c.value))
-            .map(|c| c.coordinates)
-            .collect()
-    }
-
-    pub fn get_char_at(&self, location: &Coordinates2D) -> Result<char, ()> {
-        if self.get_bounds().is_in_bounds(location) {
-            Ok(self.matrix[location.y as usize][location.x as usize])
-        } else {
-            Err(())
-        }
-    }
-
-    pub fn get_all_chars(&self) -> Vec<CharMatrixElement> {
-        self.matrix
-            .iter()
-            .enumerate()
-            .flat_map(|(y, chars)| {
-                chars
-                    .iter()
-                    .enumerate()
-                    .map(|(x, ch)| CharMatrixElement {
-                        coordinates: Coordinates2D::new(x as isize, y as isize),
-                        value: *ch,
-                    })
-                    .collect::<Vec<CharMatrixElement>>()
-            })
-            .collect()
-    }
-
-    pub fn search_text(&self) -> usize {
-        let central_char = 'A';
-        let search_direct = String::from("MAS");
-        let search_reversed = String::from("SAM");
-
-        let text_len = 3;
-        let mut total_matchs = 0;
-        for ordinate in 1..(self.matrix.len() - 1) {
-            for abscissa in 1..(self.matrix[ordinate].len() - 1) {
-                let char = self.matrix[ordinate][abscissa];
-                if char == central_char {
-                    println!("Found char at {},{}", ordinate, abscissa);
-
-                    // first diagonal
-                    let south_east = self.read_text(
-                        &Coordinates2D::new((abscissa - 1) as isize, (ordinate - 1) as isize),
-                        &SouthEast,
-                        text_len,
-                    );
-                    // second diagonal
-                    let north_east = self.read_text(
-                        &Coordinates2D::new((abscissa - 1) as isize, (ordinate + 1) as isize),
-                        &NorthEast,
-                        text_len,
-                    );
-                    let x_mas_present = (south_east == search_direct
-                        || south_east == search_reversed)
-                        && (north_east == search_direct || north_east == search_reversed);
-                    // println!("south_east={}, north_east={}, result={}",south_east, north_east, x_mas_present);
-                    if x_mas_present {
-                        total_matchs += 1;
-                    }
-                }
-            }
-        }
-        total_matchs
-    }
-
-    fn read_text(&self, start: &Coordinates2D, dir: &Direction, len: usize) -> String {
-        let mut buffer = String::new();
-        let mut read_loc: Coordinates2D = start.clone();
-        let search_bounds = self.get_bounds();
-        for _i in 0..len {
-            if !search_bounds.is_in_bounds(&read_loc) {
-                break;
-            }
-            buffer.push(self.read_value(&read_loc));
-            read_loc = dir.advance(&read_loc);
-        }
-        buffer
-    }
-
-    fn read_value(&self, coordinates: &Coordinates2D) -> char {
-        self.matrix[coordinates.y as usize][coordinates.x as usize]
-    }
-
-    // maybe should cache this if we have a performance problem
-    pub fn get_bounds(&self) -> Rectangle {
-        Rectangle::new(
-            Coordinates2D::new(0, 0),
-            self.matrix[0].len(), // breaks if no line
-            self.matrix.len(),
-        )
-    }
-
-    pub fn is_in_bounds(&self, location: &Coordinates2D) -> bool {
-        self.get_bounds().is_in_bounds(location)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_coordinates() {
+        assert_eq!(
+            Ok(Coordinates2D::new(7, -1)),
+            Coordinates2D::from_str("7,-1")
+        );
+        assert_eq!(3, Coordinates2D::from_str("7,x").unwrap_err().column);
+        assert_eq!(
+            "line 1, column 2: expected a comma",
+            Coordinates2D::from_str("7").unwrap_err().to_string()
+        );
+    }
+
     #[test]
     fn test_intersection_crossing() {
         // Horizontal and vertical segments crossing
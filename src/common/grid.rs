@@ -0,0 +1,795 @@
+// Grids of cells parsed from the characters of most puzzle inputs
+
+use crate::common::geometry::Direction::{NorthEast, SouthEast};
+use crate::common::geometry::{Coordinates2D, Direction, Rectangle, Vector};
+use crate::common::parse::{lines, parse_lines, ParseError};
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// Offsets of the orthogonal neighbours: north, east, south then west
+pub const NEIGHBOURS4: [Vector; 4] = [
+    Vector::new(0, -1),
+    Vector::new(1, 0),
+    Vector::new(0, 1),
+    Vector::new(-1, 0),
+];
+
+/// Offsets of all the neighbours clockwise from the north, like `Direction`
+pub const NEIGHBOURS8: [Vector; 8] = [
+    Vector::new(0, -1),
+    Vector::new(1, -1),
+    Vector::new(1, 0),
+    Vector::new(1, 1),
+    Vector::new(0, 1),
+    Vector::new(-1, 1),
+    Vector::new(-1, 0),
+    Vector::new(-1, -1),
+];
+
+/// Rectangular grid of cells stored row after row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid from its cells given row after row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(width * height, cells.len(), "{}x{} cells", width, height);
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Grid with each cell computed from its location
+    pub fn from_fn(width: usize, height: usize, cell: impl Fn(Coordinates2D) -> T) -> Grid<T> {
+        let cells = (0..height as isize)
+            .flat_map(|y| (0..width as isize).map(move |x| Coordinates2D::new(x, y)))
+            .map(cell)
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses each character of the lines into a cell, all lines as long as the first one
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        parse_cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let Some(width) = lines(input).next().map(|l| l.chars().count()) else {
+            return Err(ParseError::at_end(input, "a line"));
+        };
+        let rows = parse_lines(input, |line| {
+            let mut row = Vec::with_capacity(width);
+            for (index, (offset, c)) in line.char_indices().enumerate() {
+                let token = &line[offset..offset + c.len_utf8()];
+                if index == width {
+                    return Err(ParseError::at(line, token, "the end of the line"));
+                }
+                row.push(parse_cell(c).ok_or_else(|| ParseError::at(line, token, expected))?);
+            }
+            if row.len() < width {
+                return Err(ParseError::at_end(line, &format!("{} characters", width)));
+            }
+            Ok(row)
+        })?;
+        let height = rows.len();
+        Ok(Grid::new(
+            width,
+            height,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Rectangle {
+        Rectangle::new(Coordinates2D::new(0, 0), self.width, self.height)
+    }
+
+    pub fn is_in_bounds(&self, location: &Coordinates2D) -> bool {
+        self.index_of(location).is_some()
+    }
+
+    fn index_of(&self, location: &Coordinates2D) -> Option<usize> {
+        let (x, y) = (
+            usize::try_from(location.x).ok()?,
+            usize::try_from(location.y).ok()?,
+        );
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    fn coordinates_of(&self, index: usize) -> Coordinates2D {
+        Coordinates2D::new((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    /// Cell at the location, `None` out of the grid
+    pub fn get(&self, location: &Coordinates2D) -> Option<&T> {
+        self.index_of(location).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, location: &Coordinates2D) -> Option<&mut T> {
+        self.index_of(location).map(|index| &mut self.cells[index])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of {}", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell with its location, row after row
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinates2D, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.coordinates_of(index), cell))
+    }
+
+    /// Locations of the cells matching the predicate, row after row
+    pub fn positions<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Coordinates2D> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(location, _)| location)
+    }
+
+    /// Cells at the given offsets from the location that are in the grid, with their location
+    pub fn stencil<'a>(
+        &'a self,
+        location: &Coordinates2D,
+        offsets: &'a [Vector],
+    ) -> impl Iterator<Item = (Coordinates2D, &'a T)> + 'a {
+        let location = *location;
+        offsets.iter().filter_map(move |offset| {
+            let neighbour = offset.move_from(&location);
+            self.get(&neighbour).map(|cell| (neighbour, cell))
+        })
+    }
+
+    pub fn neighbours4(
+        &self,
+        location: &Coordinates2D,
+    ) -> impl Iterator<Item = (Coordinates2D, &T)> {
+        self.stencil(location, &NEIGHBOURS4)
+    }
+
+    pub fn neighbours8(
+        &self,
+        location: &Coordinates2D,
+    ) -> impl Iterator<Item = (Coordinates2D, &T)> {
+        self.stencil(location, &NEIGHBOURS8)
+    }
+
+    /// Same location in the grid repeated in every direction, like on a torus. `None` when the grid
+    /// is empty
+    pub fn wrap(&self, location: &Coordinates2D) -> Option<Coordinates2D> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        Some(Coordinates2D::new(
+            location.x.rem_euclid(self.width as isize),
+            location.y.rem_euclid(self.height as isize),
+        ))
+    }
+
+    /// Cells at the given offsets from the location, going out on one edge coming back on the
+    /// other. None in an empty grid
+    pub fn stencil_wrapping<'a>(
+        &'a self,
+        location: &Coordinates2D,
+        offsets: &'a [Vector],
+    ) -> impl Iterator<Item = (Coordinates2D, &'a T)> + 'a {
+        let location = *location;
+        offsets.iter().filter_map(move |offset| {
+            let neighbour = self.wrap(&offset.move_from(&location))?;
+            Some((neighbour, &self[neighbour]))
+        })
+    }
+
+    pub fn neighbours4_wrapping(
+        &self,
+        location: &Coordinates2D,
+    ) -> impl Iterator<Item = (Coordinates2D, &T)> {
+        self.stencil_wrapping(location, &NEIGHBOURS4)
+    }
+
+    pub fn neighbours8_wrapping(
+        &self,
+        location: &Coordinates2D,
+    ) -> impl Iterator<Item = (Coordinates2D, &T)> {
+        self.stencil_wrapping(location, &NEIGHBOURS8)
+    }
+
+    /// Grid of the same size with each cell converted
+    pub fn map<U>(&self, convert: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.iter().map(convert).collect(),
+        )
+    }
+}
+
+/// Transforms building a new grid from the cells of this one
+impl<T: Clone> Grid<T> {
+    fn cell(&self, x: usize, y: usize) -> T {
+        self.cells[y * self.width + x].clone()
+    }
+
+    /// Rows become columns, the diagonal from the top left corner staying in place
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |c| {
+            self.cell(c.y as usize, c.x as usize)
+        })
+    }
+
+    /// Rotated clockwise, the first column becoming the first row
+    pub fn rotate_90(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |c| {
+            self.cell(c.y as usize, self.height - 1 - c.x as usize)
+        })
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        self.flip_horizontal().flip_vertical()
+    }
+
+    /// Rotated counterclockwise, the last column becoming the first row
+    pub fn rotate_270(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |c| {
+            self.cell(self.width - 1 - c.y as usize, c.x as usize)
+        })
+    }
+
+    /// Mirrored left to right
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |c| {
+            self.cell(self.width - 1 - c.x as usize, c.y as usize)
+        })
+    }
+
+    /// Mirrored upside down
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |c| {
+            self.cell(c.x as usize, self.height - 1 - c.y as usize)
+        })
+    }
+
+    /// Cells of the area, `None` when it is not entirely in the grid
+    pub fn sub_grid(&self, area: &Rectangle) -> Option<Grid<T>> {
+        let corner = Coordinates2D::new(
+            area.location.x + area.width as isize - 1,
+            area.location.y + area.height as isize - 1,
+        );
+        if area.area() > 0 && !(self.is_in_bounds(&area.location) && self.is_in_bounds(&corner)) {
+            return None;
+        }
+        Some(Grid::from_fn(area.width, area.height, |c| {
+            self[Coordinates2D::new(area.location.x + c.x, area.location.y + c.y)].clone()
+        }))
+    }
+
+    /// This grid repeated `across` times to the right and `down` times below
+    pub fn tile(&self, across: usize, down: usize) -> Grid<T> {
+        Grid::from_fn(self.width * across, self.height * down, |c| {
+            self.cell(c.x as usize % self.width, c.y as usize % self.height)
+        })
+    }
+
+    /// The 8 ways to place the grid by rotating and flipping it, starting with itself
+    pub fn symmetries(&self) -> [Grid<T>; 8] {
+        let flipped = self.flip_horizontal();
+        [
+            self.clone(),
+            self.rotate_90(),
+            self.rotate_180(),
+            self.rotate_270(),
+            flipped.rotate_90(),
+            flipped.rotate_180(),
+            flipped.rotate_270(),
+            flipped,
+        ]
+    }
+
+    /// Whether the other grid is this one rotated or flipped
+    pub fn eq_up_to_symmetry(&self, other: &Grid<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.symmetries().contains(other)
+    }
+}
+
+/// Panics out of the grid, `get` being the checked access
+impl<T> Index<Coordinates2D> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, location: Coordinates2D) -> &T {
+        match self.index_of(&location) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "{:?} out of a {}x{} grid",
+                location, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Coordinates2D> for Grid<T> {
+    fn index_mut(&mut self, location: Coordinates2D) -> &mut T {
+        match self.index_of(&location) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "{:?} out of a {}x{} grid",
+                location, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Grid::parse(input, "a character", Some)
+    }
+}
+
+/// One line per row, like the puzzle inputs
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Shorter rows are completed with '.', as `CharMatrix::set_char` does
+impl From<&CharMatrix> for Grid<char> {
+    fn from(matrix: &CharMatrix) -> Self {
+        let width = matrix.matrix.iter().map(Vec::len).max().unwrap_or(0);
+        let cells = matrix
+            .matrix
+            .iter()
+            .flat_map(|row| {
+                row.iter()
+                    .copied()
+                    .chain(std::iter::repeat('.'))
+                    .take(width)
+            })
+            .collect();
+        Grid::new(width, matrix.matrix.len(), cells)
+    }
+}
+
+impl From<&Grid<char>> for CharMatrix {
+    fn from(grid: &Grid<char>) -> Self {
+        CharMatrix {
+            matrix: grid.rows().map(<[char]>::to_vec).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CharMatrix {
+    matrix: Vec<Vec<char>>,
+}
+
+impl CharMatrix {
+    pub fn copy_filled(&self, replacement: char) -> CharMatrix {
+        let mut new_matrix = CharMatrix::new();
+
+        self.matrix
+            .iter()
+            .map(|row| row.iter().map(|_| replacement).collect())
+            .for_each(|row| new_matrix.insert_row(row));
+        new_matrix
+    }
+
+    pub fn print(&self) -> String {
+        self.matrix
+            .iter()
+            .map(|row| row.iter().collect::<String>())
+            .map(|row| format!("{}\n", row))
+            .collect()
+    }
+}
+
+impl FromStr for CharMatrix {
+    type Err = Infallible;
+
+    /// Parses lines to create a matrix of chars, skipping empty lines. Each row keeps the length of
+    /// its line, use `Grid` to reject ragged input
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(CharMatrix {
+            matrix: input
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(|line| line.chars().collect())
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CharMatrixElement {
+    pub coordinates: Coordinates2D,
+    pub value: char,
+}
+
+impl CharMatrix {
+    pub fn new() -> Self {
+        CharMatrix { matrix: Vec::new() }
+    }
+
+    pub fn insert_row(&mut self, row: Vec<char>) {
+        self.matrix.push(row);
+    }
+
+    pub fn set_char(&mut self, value: char, location: &Coordinates2D) {
+        self.ensure_y(1 + location.y as usize);
+        self.ensure_x(1 + location.x as usize);
+        self.matrix[location.y as usize][location.x as usize] = value;
+    }
+
+    pub fn ensure_y(&mut self, height: usize) {
+        if self.matrix.len() < height {
+            for _ in self.matrix.len()..height {
+                self.matrix.push(Vec::new());
+            }
+        }
+    }
+
+    pub fn ensure_x(&mut self, width: usize) {
+        for row in self.matrix.iter_mut() {
+            if row.len() < width {
+                for _ in row.len()..width {
+                    row.push('.');
+                }
+            }
+        }
+    }
+
+    /// get coordinates of the first char matching search
+    pub fn search_char(&self, search: &char) -> Option<Coordinates2D> {
+        for ordinate in 1..(self.matrix.len() - 1) {
+            for abscissa in 1..(self.matrix[ordinate].len() - 1) {
+                let char = self.matrix[ordinate][abscissa];
+                if search.eq(&char) {
+                    return Some(Coordinates2D::new(abscissa as isize, ordinate as isize));
+                }
+            }
+        }
+        None
+    }
+
+    pub fn search_chars(&self, search: &char) -> Vec<Coordinates2D> {
+        self.get_all_chars()
+            .iter()
+            .filter(|c| search.eq(&c.value))
+            .map(|c| c.coordinates)
+            .collect()
+    }
+
+    /// Returns `None` out of the matrix
+    pub fn get_char_at(&self, location: &Coordinates2D) -> Option<char> {
+        self.matrix
+            .get(location.y as usize)?
+            .get(location.x as usize)
+            .copied()
+    }
+
+    pub fn get_all_chars(&self) -> Vec<CharMatrixElement> {
+        self.matrix
+            .iter()
+            .enumerate()
+            .flat_map(|(y, chars)| {
+                chars
+                    .iter()
+                    .enumerate()
+                    .map(|(x, ch)| CharMatrixElement {
+                        coordinates: Coordinates2D::new(x as isize, y as isize),
+                        value: *ch,
+                    })
+                    .collect::<Vec<CharMatrixElement>>()
+            })
+            .collect()
+    }
+
+    pub fn search_text(&self) -> usize {
+        let central_char = 'A';
+        let search_direct = String::from("MAS");
+        let search_reversed = String::from("SAM");
+
+        let text_len = 3;
+        let mut total_matchs = 0;
+        for ordinate in 1..(self.matrix.len() - 1) {
+            for abscissa in 1..(self.matrix[ordinate].len() - 1) {
+                let char = self.matrix[ordinate][abscissa];
+                if char == central_char {
+                    println!("Found char at {},{}", ordinate, abscissa);
+
+                    // first diagonal
+                    let south_east = self.read_text(
+                        &Coordinates2D::new((abscissa - 1) as isize, (ordinate - 1) as isize),
+                        &SouthEast,
+                        text_len,
+                    );
+                    // second diagonal
+                    let north_east = self.read_text(
+                        &Coordinates2D::new((abscissa - 1) as isize, (ordinate + 1) as isize),
+                        &NorthEast,
+                        text_len,
+                    );
+                    let x_mas_present = (south_east == search_direct
+                        || south_east == search_reversed)
+                        && (north_east == search_direct || north_east == search_reversed);
+                    // println!("south_east={}, north_east={}, result={}",south_east, north_east, x_mas_present);
+                    if x_mas_present {
+                        total_matchs += 1;
+                    }
+                }
+            }
+        }
+        total_matchs
+    }
+
+    fn read_text(&self, start: &Coordinates2D, dir: &Direction, len: usize) -> String {
+        let mut buffer = String::new();
+        let mut read_loc: Coordinates2D = *start;
+        let search_bounds = self.get_bounds();
+        for _i in 0..len {
+            if !search_bounds.is_in_bounds(&read_loc) {
+                break;
+            }
+            buffer.push(self.read_value(&read_loc));
+            read_loc = dir.advance(&read_loc);
+        }
+        buffer
+    }
+
+    fn read_value(&self, coordinates: &Coordinates2D) -> char {
+        self.matrix[coordinates.y as usize][coordinates.x as usize]
+    }
+
+    // maybe should cache this if we have a performance problem
+    pub fn get_bounds(&self) -> Rectangle {
+        Rectangle::new(
+            Coordinates2D::new(0, 0),
+            self.matrix.first().map_or(0, Vec::len),
+            self.matrix.len(),
+        )
+    }
+
+    pub fn is_in_bounds(&self, location: &Coordinates2D) -> bool {
+        self.get_bounds().is_in_bounds(location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ragged() {
+        let matrix = CharMatrix::from_str("abc\nde\n\nfghi\n").unwrap();
+
+        assert_eq!("abc\nde\nfghi\n", matrix.print());
+        assert_eq!(Some('e'), matrix.get_char_at(&Coordinates2D::new(1, 1)));
+        assert_eq!(None, matrix.get_char_at(&Coordinates2D::new(2, 1)));
+        assert_eq!(Some('i'), matrix.get_char_at(&Coordinates2D::new(3, 2)));
+        assert_eq!(None, matrix.get_char_at(&Coordinates2D::new(-1, 0)));
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let matrix = CharMatrix::from_str("\n").unwrap();
+
+        assert_eq!(CharMatrix::new(), matrix);
+        assert_eq!(None, matrix.get_char_at(&Coordinates2D::new(0, 0)));
+        assert!(!matrix.is_in_bounds(&Coordinates2D::new(0, 0)));
+    }
+
+    #[test]
+    fn test_grid_parse() {
+        let grid = Grid::parse("123\n456\n", "a digit", |c| c.to_digit(10)).unwrap();
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[Coordinates2D::new(2, 1)]);
+        assert_eq!(Some(&4), grid.get(&Coordinates2D::new(0, 1)));
+        assert_eq!(None, grid.get(&Coordinates2D::new(3, 0)));
+        assert_eq!(None, grid.get(&Coordinates2D::new(0, -1)));
+        assert_eq!(
+            "line 2, column 2: expected a digit",
+            Grid::parse("123\n4x6", "a digit", |c| c.to_digit(10))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_grid_rows_and_columns() {
+        let mut grid = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        grid[Coordinates2D::new(1, 0)] = 7;
+
+        assert_eq!(
+            vec![&[1, 7, 3][..], &[4, 5, 6][..]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![7, 5], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(3, grid.columns().count());
+        assert_eq!(
+            vec![Coordinates2D::new(1, 0), Coordinates2D::new(2, 1)],
+            grid.positions(|cell| *cell > 5).collect::<Vec<_>>()
+        );
+        assert_eq!("173\n456\n", grid.to_string());
+    }
+
+    #[test]
+    fn test_grid_from_char_matrix() {
+        let mut matrix = CharMatrix::from_str("ab\ncd").unwrap();
+        let grid = Grid::from(&matrix);
+
+        assert_eq!('c', grid[Coordinates2D::new(0, 1)]);
+        assert_eq!(matrix, CharMatrix::from(&grid));
+
+        assert_eq!(Some('d'), matrix.get_char_at(&Coordinates2D::new(1, 1)));
+        assert_eq!(None, matrix.get_char_at(&Coordinates2D::new(2, 0)));
+
+        matrix.insert_row(vec!['e']);
+        assert_eq!("ab\ncd\ne.\n", Grid::from(&matrix).to_string());
+        assert_eq!(None, matrix.get_char_at(&Coordinates2D::new(1, 2)));
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        let grid = Grid::new(3, 3, (1..=9).collect());
+        let values = |cells: Vec<(Coordinates2D, &i32)>| -> Vec<i32> {
+            cells.into_iter().map(|(_, cell)| *cell).collect()
+        };
+        let corner = Coordinates2D::new(0, 0);
+        let center = Coordinates2D::new(1, 1);
+
+        assert_eq!(
+            vec![2, 6, 8, 4],
+            values(grid.neighbours4(&center).collect())
+        );
+        assert_eq!(vec![2, 4], values(grid.neighbours4(&corner).collect()));
+        assert_eq!(
+            vec![2, 3, 6, 9, 8, 7, 4, 1],
+            values(grid.neighbours8(&center).collect())
+        );
+        assert_eq!(vec![2, 5, 4], values(grid.neighbours8(&corner).collect()));
+        assert_eq!(
+            vec![(Coordinates2D::new(2, 0), &3)],
+            grid.stencil(&corner, &[Vector::new(2, 0), Vector::new(3, 0)])
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_grid_neighbours_wrapping() {
+        let grid = Grid::new(3, 2, (1..=6).collect());
+        let corner = Coordinates2D::new(0, 0);
+
+        assert_eq!(
+            Some(Coordinates2D::new(2, 1)),
+            grid.wrap(&Coordinates2D::new(-1, -3))
+        );
+        assert_eq!(
+            vec![4, 2, 4, 3],
+            grid.neighbours4_wrapping(&corner)
+                .map(|(_, cell)| *cell)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(8, grid.neighbours8_wrapping(&corner).count());
+        assert_eq!(
+            vec![(Coordinates2D::new(1, 0), &2)],
+            grid.stencil_wrapping(&corner, &[Vector::new(4, 2)])
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_empty_grid_wrapping() {
+        let grid: Grid<u8> = Grid::new(0, 0, vec![]);
+        let corner = Coordinates2D::new(0, 0);
+
+        assert_eq!(None, grid.wrap(&corner));
+        assert_eq!(0, grid.neighbours4_wrapping(&corner).count());
+        assert_eq!(0, grid.neighbours8_wrapping(&corner).count());
+        assert_eq!(
+            0,
+            Grid::new(3, 0, vec![0; 0])
+                .neighbours4_wrapping(&corner)
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_grid_transforms() {
+        let grid = Grid::from_str("abc\ndef\n").unwrap();
+
+        assert_eq!("ad\nbe\ncf\n", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc\n", grid.rotate_90().to_string());
+        assert_eq!("fed\ncba\n", grid.rotate_180().to_string());
+        assert_eq!("cf\nbe\nad\n", grid.rotate_270().to_string());
+        assert_eq!("cba\nfed\n", grid.flip_horizontal().to_string());
+        assert_eq!("def\nabc\n", grid.flip_vertical().to_string());
+        assert_eq!(grid, grid.rotate_90().rotate_270());
+    }
+
+    #[test]
+    fn test_grid_sub_grid_and_tile() {
+        let grid = Grid::from_str("abc\ndef\n").unwrap();
+
+        assert_eq!(
+            "bc\nef\n",
+            grid.sub_grid(&Rectangle::new(Coordinates2D::new(1, 0), 2, 2))
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            None,
+            grid.sub_grid(&Rectangle::new(Coordinates2D::new(2, 0), 2, 2))
+        );
+        assert_eq!(
+            "abcabc\ndefdef\nabcabc\ndefdef\n",
+            grid.tile(2, 2).to_string()
+        );
+    }
+
+    #[test]
+    fn test_grid_symmetries() {
+        let grid = Grid::from_str("ab\ncd\n").unwrap();
+
+        let symmetries: Vec<String> = grid.symmetries().iter().map(Grid::to_string).collect();
+
+        assert_eq!(
+            vec![
+                "ab\ncd\n", "ca\ndb\n", "dc\nba\n", "bd\nac\n", "db\nca\n", "cd\nab\n", "ac\nbd\n",
+                "ba\ndc\n"
+            ],
+            symmetries
+        );
+        assert!(grid.eq_up_to_symmetry(&Grid::from_str("ac\nbd").unwrap()));
+        assert!(!grid.eq_up_to_symmetry(&Grid::from_str("ab\ndc").unwrap()));
+    }
+}
@@ -0,0 +1,13 @@
+// Toolkit shared by the puzzles of every year
+
+pub mod geometry;
+pub mod grid;
+pub mod parse;
+pub mod range;
+pub mod regions;
+pub mod search;
+
+pub use geometry::{Coordinates2D, Direction, Rectangle, Segment, Vector};
+pub use grid::{CharMatrix, CharMatrixElement, Grid, NEIGHBOURS4, NEIGHBOURS8};
+pub use parse::ParseError;
+pub use range::Range;
@@ -0,0 +1,208 @@
+// Helpers reading the usual shapes of puzzle inputs, and reporting where they are malformed
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Position in the input where parsing failed, and what was expected there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Starting at 1
+    pub line: usize,
+    /// Starting at 1, in characters
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Error at the start of `token`, which must be a slice of `text`
+    pub fn at(text: &str, token: &str, expected: &str) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|offset| *offset <= text.len())
+            .unwrap_or(0);
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Error at the end of `text`, when something is missing
+    pub fn at_end(text: &str, expected: &str) -> ParseError {
+        ParseError::at(text, &text[text.len()..], expected)
+    }
+
+    /// Same error located in `text`, for an error found in `part` which must be a slice of it
+    pub fn within(self, text: &str, part: &str) -> ParseError {
+        let start = ParseError::at(text, part, &self.expected);
+        ParseError {
+            line: start.line + self.line - 1,
+            column: match self.line {
+                1 => start.column + self.column - 1,
+                _ => self.column,
+            },
+            expected: self.expected,
+        }
+    }
+
+    /// Same error in a text starting at line `line` of the input
+    pub fn on_line(self, line: usize) -> ParseError {
+        ParseError {
+            line: self.line + line - 1,
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `text`, reporting its position when it is not a `T`
+pub fn token<T: FromStr>(text: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    let token = token.trim();
+    token
+        .parse()
+        .map_err(|_| ParseError::at(text, token, expected))
+}
+
+/// Parses each non empty line, errors are located in the whole input
+pub fn parse_lines<T>(
+    input: &str,
+    parser: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parser(line).map_err(|e| e.on_line(index + 1)))
+        .collect()
+}
+
+/// Parses each block of lines separated by an empty line, errors are located in the whole input
+pub fn parse_blocks<T>(
+    input: &str,
+    parser: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut line = 1;
+    let mut parsed = Vec::new();
+    for block in input.split("\n\n") {
+        if !block.trim().is_empty() {
+            parsed.push(parser(block).map_err(|e| e.on_line(line))?);
+        }
+        line += block.lines().count() + 1;
+    }
+    Ok(parsed)
+}
+
+/// Lines of the input, without the empty ones like the trailing one
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().filter(|l| !l.trim().is_empty())
+}
+
+/// Blocks of lines separated by an empty line
+pub fn blocks(input: &str) -> Vec<Vec<&str>> {
+    input
+        .split("\n\n")
+        .map(|block| lines(block).collect::<Vec<&str>>())
+        .filter(|block| !block.is_empty())
+        .collect()
+}
+
+/// Every integer of the text, in order, with their sign
+pub fn numbers<T: FromStr>(text: &str) -> Vec<T> {
+    let mut numbers = Vec::new();
+    let mut current = String::new();
+    for char in text.chars().chain([' ']) {
+        if char.is_ascii_digit() || (char == '-' && current.is_empty()) {
+            current.push(char);
+        } else {
+            if let Ok(number) = current.parse() {
+                numbers.push(number);
+            }
+            current.clear();
+            if char == '-' {
+                current.push(char);
+            }
+        }
+    }
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_and_blocks() {
+        let input = "a\nb\n\nc\n";
+
+        assert_eq!(vec!["a", "b", "c"], lines(input).collect::<Vec<&str>>());
+        assert_eq!(vec![vec!["a", "b"], vec!["c"]], blocks(input));
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(
+            vec![94, 34, -8400, 5400],
+            numbers::<isize>("Button A: X+94, Y+34\nPrize: X=-8400, Y=5400")
+        );
+        assert_eq!(vec![3, 4], numbers::<usize>("3   4"));
+        assert!(numbers::<usize>("a - b").is_empty());
+    }
+
+    #[test]
+    fn test_error_position() {
+        let line = "12,x4";
+
+        assert_eq!(
+            ParseError {
+                line: 1,
+                column: 4,
+                expected: String::from("a number"),
+            },
+            token::<usize>(line, &line[3..], "a number").unwrap_err()
+        );
+        assert_eq!(6, ParseError::at_end(line, "a comma").column);
+        let text = "1,2\n3,x\n";
+        assert_eq!(
+            ParseError::at(text, &text[6..], "a number"),
+            ParseError::at(&text[4..], &text[6..], "a number").within(text, &text[4..])
+        );
+        assert_eq!(
+            "line 2, column 3: expected a number",
+            ParseError::at(text, &text[6..], "a number").to_string()
+        );
+        assert_eq!(
+            "line 3, column 4: expected a number",
+            ParseError::at(line, &line[3..], "a number")
+                .on_line(3)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_and_blocks() {
+        let parse = |line: &str| token::<usize>(line, line, "a number");
+
+        assert_eq!(Ok(vec![1, 2]), parse_lines("1\n\n2\n", parse));
+        assert_eq!(3, parse_lines("1\n\nx\n", parse).unwrap_err().line);
+        assert_eq!(
+            5,
+            parse_blocks("1\n2\n\n3\nx", |block| parse_lines(block, parse))
+                .unwrap_err()
+                .line
+        );
+    }
+}
@@ -0,0 +1,69 @@
+// Ranges of integers with both bounds included, as written like "11-22" in the inputs
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::common::parse::{token, ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Range {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Range {
+    pub fn contains_inclusive(&self, value: &usize) -> bool {
+        *value >= self.start && *value <= self.end
+    }
+
+    pub fn values(&self) -> RangeInclusive<usize> {
+        self.start..=self.end
+    }
+}
+
+impl<'a> IntoIterator for &'a Range {
+    type Item = &'a usize;
+    type IntoIter = std::array::IntoIter<&'a usize, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        [&self.start, &self.end].into_iter()
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    /// Parses "start-end"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::at_end(s, "a dash"))?;
+        Ok(Range {
+            start: token(s, start, "a start")?,
+            end: token(s, end, "an end")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let range = Range::from_str("95-115").unwrap();
+
+        assert_eq!(
+            Range {
+                start: 95,
+                end: 115
+            },
+            range
+        );
+        assert!(range.contains_inclusive(&115));
+        assert!(!range.contains_inclusive(&116));
+        assert_eq!(21, range.values().count());
+        assert!(Range::from_str("95").is_err());
+        assert!(Range::from_str("a-b").is_err());
+    }
+}
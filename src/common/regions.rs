@@ -0,0 +1,189 @@
+// Connected regions of equal cells in a grid, with their area, perimeter and sides
+
+use crate::common::geometry::{Coordinates2D, Vector};
+use crate::common::grid::{Grid, NEIGHBOURS4};
+use crate::common::search::{bfs, open_moves};
+
+/// Cells of equal value reachable from each other with orthogonal moves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<T> {
+    pub id: usize,
+    pub value: T,
+    /// Row after row
+    cells: Vec<Coordinates2D>,
+    perimeter: usize,
+    sides: usize,
+}
+
+impl<T> Region<T> {
+    pub fn cells(&self) -> &[Coordinates2D] {
+        &self.cells
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Number of cell edges on the border of the region, holes included
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    /// Number of straight sides of the border, which is also its number of corners
+    pub fn sides(&self) -> usize {
+        self.sides
+    }
+}
+
+/// Every region of a grid, numbered in the order their first cell is met row after row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions<T> {
+    ids: Grid<usize>,
+    regions: Vec<Region<T>>,
+}
+
+impl<T: Clone + PartialEq> Regions<T> {
+    /// Flood fills the grid from each cell not in a region yet
+    pub fn of(grid: &Grid<T>) -> Regions<T> {
+        let mut ids: Grid<Option<usize>> = grid.map(|_| None);
+        let mut regions = Vec::new();
+        for (start, value) in grid.iter() {
+            if ids[start].is_some() {
+                continue;
+            }
+            let id = regions.len();
+            let reached = bfs(start, open_moves(grid, |cell| cell == value));
+            let mut cells: Vec<Coordinates2D> = reached.distances().keys().copied().collect();
+            cells.sort_by_key(|cell| (cell.y, cell.x));
+            for cell in &cells {
+                ids[*cell] = Some(id);
+            }
+            regions.push(Region {
+                id,
+                value: value.clone(),
+                cells,
+                perimeter: 0,
+                sides: 0,
+            });
+        }
+        let ids = ids.map(|id| id.expect("every cell is in a region"));
+        for region in regions.iter_mut() {
+            region.perimeter = perimeter(&ids, region);
+            region.sides = corners(&ids, region);
+        }
+        Regions { ids, regions }
+    }
+}
+
+impl<T> Regions<T> {
+    /// Id of the region of each cell
+    pub fn ids(&self) -> &Grid<usize> {
+        &self.ids
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region<T>> {
+        self.regions.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Region of the cell, `None` out of the grid
+    pub fn region_at(&self, location: &Coordinates2D) -> Option<&Region<T>> {
+        self.ids.get(location).map(|id| &self.regions[*id])
+    }
+}
+
+fn perimeter<T>(ids: &Grid<usize>, region: &Region<T>) -> usize {
+    region
+        .cells
+        .iter()
+        .map(|cell| {
+            NEIGHBOURS4
+                .iter()
+                .filter(|offset| ids.get(&offset.move_from(cell)) != Some(&region.id))
+                .count()
+        })
+        .sum()
+}
+
+/// Each cell has a corner between two consecutive orthogonal neighbours when both are out of the
+/// region, or both in it with the cell in the diagonal out of it
+fn corners<T>(ids: &Grid<usize>, region: &Region<T>) -> usize {
+    let inside = |cell: &Coordinates2D, offset: &Vector| {
+        ids.get(&offset.move_from(cell)) == Some(&region.id)
+    };
+    region
+        .cells
+        .iter()
+        .map(|cell| {
+            (0..NEIGHBOURS4.len())
+                .filter(|i| {
+                    let first = NEIGHBOURS4[*i];
+                    let second = NEIGHBOURS4[(i + 1) % NEIGHBOURS4.len()];
+                    let diagonal = Vector::new(first.x + second.x, first.y + second.y);
+                    match (inside(cell, &first), inside(cell, &second)) {
+                        (false, false) => true,
+                        (true, true) => !inside(cell, &diagonal),
+                        _ => false,
+                    }
+                })
+                .count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_regions() {
+        let grid = Grid::from_str("AAAA\nBBCD\nBBCC\nEEEC\n").unwrap();
+
+        let regions = Regions::of(&grid);
+
+        let summary: Vec<(char, usize, usize, usize)> = regions
+            .iter()
+            .map(|r| (r.value, r.area(), r.perimeter(), r.sides()))
+            .collect();
+        assert_eq!(
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ],
+            summary
+        );
+        assert_eq!(2, regions.ids()[Coordinates2D::new(3, 3)]);
+        assert_eq!(
+            &[Coordinates2D::new(3, 1)],
+            regions
+                .region_at(&Coordinates2D::new(3, 1))
+                .unwrap()
+                .cells()
+        );
+    }
+
+    #[test]
+    fn test_region_with_holes() {
+        let grid = Grid::from_str("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n").unwrap();
+
+        let regions = Regions::of(&grid);
+
+        let outer = regions.region_at(&Coordinates2D::new(0, 0)).unwrap();
+        assert_eq!(5, regions.len());
+        assert_eq!(
+            (21, 36, 20),
+            (outer.area(), outer.perimeter(), outer.sides())
+        );
+    }
+}
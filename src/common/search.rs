@@ -0,0 +1,292 @@
+// Shortest paths from a start state: breadth first search, Dijkstra and A*
+
+use crate::common::geometry::Coordinates2D;
+use crate::common::grid::{CharMatrix, Grid};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Shortest distances from the start to every state reached, and how they were reached
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    start: S,
+    distances: HashMap<S, usize>,
+    /// Every previous state on a shortest path
+    predecessors: HashMap<S, Vec<S>>,
+    /// Number of shortest paths from the start
+    counts: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new(start: S) -> Search<S> {
+        Search {
+            distances: HashMap::from([(start.clone(), 0)]),
+            predecessors: HashMap::new(),
+            counts: HashMap::from([(start.clone(), 1)]),
+            start,
+        }
+    }
+
+    /// Records a move from a state already settled, returns whether it is shorter than the known ones
+    fn relax(&mut self, from: &S, next: S, distance: usize) -> bool {
+        let count = self.counts[from];
+        match self.distances.get(&next) {
+            Some(known) if *known < distance => false,
+            Some(known) if *known == distance => {
+                self.predecessors
+                    .entry(next.clone())
+                    .or_default()
+                    .push(from.clone());
+                *self.counts.entry(next).or_default() += count;
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), distance);
+                self.predecessors.insert(next.clone(), vec![from.clone()]);
+                self.counts.insert(next, count);
+                true
+            }
+        }
+    }
+
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// Shortest distance from the start, `None` when not reached
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// Every state reached with its shortest distance
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    /// Previous states on the shortest paths to the state
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// Number of shortest paths from the start to the state, 0 when not reached
+    pub fn count_paths(&self, state: &S) -> usize {
+        self.counts.get(state).copied().unwrap_or(0)
+    }
+
+    /// One of the shortest paths, from the start to the target included
+    pub fn path(&self, target: &S) -> Option<Vec<S>> {
+        self.distances.get(target)?;
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path from the start to the target, none when not reached
+    pub fn all_paths(&self, target: &S) -> Vec<Vec<S>> {
+        if !self.distances.contains_key(target) {
+            return vec![];
+        }
+        if *target == self.start {
+            return vec![vec![target.clone()]];
+        }
+        self.predecessors(target)
+            .iter()
+            .flat_map(|previous| self.all_paths(previous))
+            .map(|mut path| {
+                path.push(target.clone());
+                path
+            })
+            .collect()
+    }
+
+    /// States on any of the shortest paths to the target, both ends included
+    pub fn on_shortest_paths(&self, target: &S) -> HashSet<S> {
+        let mut states = HashSet::new();
+        if !self.distances.contains_key(target) {
+            return states;
+        }
+        let mut to_visit = vec![target.clone()];
+        while let Some(state) = to_visit.pop() {
+            if states.insert(state.clone()) {
+                to_visit.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        states
+    }
+}
+
+/// Breadth first search, each move costing 1
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let distance = search.distances[&state] + 1;
+        for next in successors(&state) {
+            if search.relax(&state, next.clone(), distance) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra search of every state reachable with moves of the given costs, which must not be 0 for
+/// the paths to be all found
+pub fn dijkstra<S, I>(start: S, successors: impl FnMut(&S) -> I) -> Search<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    best_first(start, successors, |_| 0, |_| false).0
+}
+
+/// A* search stopping at the first goal reached, with a heuristic never above the remaining
+/// distance and decreasing by at most the cost of each move
+pub fn astar<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl Fn(&S) -> usize,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<(S, Search<S>)>
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    match best_first(start, successors, heuristic, is_goal) {
+        (search, Some(goal)) => Some((goal, search)),
+        (_, None) => None,
+    }
+}
+
+/// States are settled by estimated total distance, then by distance so that every predecessor of
+/// a state is settled before it
+fn best_first<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    heuristic: impl Fn(&S) -> usize,
+    is_goal: impl Fn(&S) -> bool,
+) -> (Search<S>, Option<S>)
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, start))]);
+    while let Some(Reverse((_, distance, state))) = queue.pop() {
+        if distance > search.distances[&state] {
+            continue;
+        }
+        if is_goal(&state) {
+            return (search, Some(state));
+        }
+        for (next, cost) in successors(&state) {
+            if search.relax(&state, next.clone(), distance + cost) {
+                let estimate = distance + cost + heuristic(&next);
+                queue.push(Reverse((estimate, distance + cost, next)));
+            }
+        }
+    }
+    (search, None)
+}
+
+/// Orthogonal moves between the cells of the grid that are open
+pub fn open_moves<'a, T>(
+    grid: &'a Grid<T>,
+    is_open: impl Fn(&T) -> bool + 'a,
+) -> impl Fn(&Coordinates2D) -> Vec<Coordinates2D> + 'a {
+    move |location| {
+        grid.neighbours4(location)
+            .filter(|(_, cell)| is_open(cell))
+            .map(|(next, _)| next)
+            .collect()
+    }
+}
+
+/// Orthogonal moves between the tiles of the map that are not a wall
+pub fn moves_avoiding(
+    map: &CharMatrix,
+    wall: char,
+) -> impl Fn(&Coordinates2D) -> Vec<Coordinates2D> {
+    let grid = Grid::from(map);
+    move |location| open_moves(&grid, |tile| *tile != wall)(location)
+}
+
+/// Same moves each costing 1, for Dijkstra or A*
+pub fn with_unit_cost<S>(moves: impl Fn(&S) -> Vec<S>) -> impl Fn(&S) -> Vec<(S, usize)> {
+    move |state| moves(state).into_iter().map(|next| (next, 1)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const MAZE: &str = "#######
+#S....#
+#.#.#.#
+#.....#
+#.###E#
+#######
+";
+
+    fn start_and_end(map: &CharMatrix) -> (Coordinates2D, Coordinates2D) {
+        (map.search_chars(&'S')[0], map.search_chars(&'E')[0])
+    }
+
+    #[test]
+    fn test_bfs() {
+        let map = CharMatrix::from_str(MAZE).unwrap();
+        let (start, end) = start_and_end(&map);
+
+        let search = bfs(start, moves_avoiding(&map, '#'));
+
+        assert_eq!(Some(7), search.distance(&end));
+        assert_eq!(None, search.distance(&Coordinates2D::new(0, 0)));
+        assert_eq!(3, search.count_paths(&end));
+        assert_eq!(3, search.all_paths(&end).len());
+        let path = search.path(&end).unwrap();
+        assert_eq!(
+            (8, Some(&start), Some(&end)),
+            (path.len(), path.first(), path.last())
+        );
+        assert_eq!(14, search.on_shortest_paths(&end).len());
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let map = CharMatrix::from_str(MAZE).unwrap();
+        let (start, end) = start_and_end(&map);
+        let moves = with_unit_cost(moves_avoiding(&map, '#'));
+        // going down costs 5
+        let successors = |from: &Coordinates2D| -> Vec<(Coordinates2D, usize)> {
+            moves(from)
+                .into_iter()
+                .map(|(next, cost)| (next, if next.y > from.y { 5 } else { cost }))
+                .collect()
+        };
+
+        let search = dijkstra(start, successors);
+        let (goal, found) = astar(
+            start,
+            successors,
+            |state| state.manhattan_distance(&end),
+            |state| *state == end,
+        )
+        .unwrap();
+
+        assert_eq!(Some(19), search.distance(&end));
+        assert_eq!(3, search.count_paths(&end));
+        assert_eq!(end, goal);
+        assert_eq!(Some(19), found.distance(&end));
+        assert_eq!(3, found.count_paths(&end));
+        assert_eq!(search.all_paths(&end).len(), found.all_paths(&end).len());
+        assert!(astar(start, successors, |_| 0, |_| false).is_none());
+    }
+}
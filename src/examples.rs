@@ -0,0 +1,246 @@
+// Example inputs and their expected answers, extracted from the puzzle descriptions
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+
+use crate::cache::{read_if_exists, write_creating_dirs};
+use crate::solver::Part;
+
+/// Answer given in the description for one of the examples
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleAnswer {
+    pub part: Part,
+    /// Index in `Fixture::examples`
+    pub example: usize,
+    pub answer: String,
+}
+
+/// Stored in `<dir>/<year>/dayDD/` as `exampleN.txt` files and an `answers.txt`
+/// with one `<part>\t<example number>\t<answer>` line per part
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fixture {
+    pub examples: Vec<String>,
+    pub answers: Vec<ExampleAnswer>,
+}
+
+impl Fixture {
+    /// Reads the `<pre><code>` blocks of the descriptions and the last emphasised code of each part,
+    /// which is the answer for the first example of the part (or of part one when it has none)
+    pub fn parse(html: &str) -> Fixture {
+        let mut fixture = Fixture::default();
+        let blocks = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+        let answers = Regex::new(r"<code><em>([^<]*)</em></code>").unwrap();
+        for (part, article) in Part::all().into_iter().zip(articles(html)) {
+            let first_example = fixture.examples.len();
+            fixture.examples.extend(
+                blocks
+                    .captures_iter(article)
+                    .map(|c| html_text(&c[1]).trim_end().to_string()),
+            );
+            let example = if fixture.examples.len() > first_example {
+                first_example
+            } else {
+                0
+            };
+            if let Some(answer) = answers.captures_iter(article).last() {
+                if !fixture.examples.is_empty() {
+                    fixture.answers.push(ExampleAnswer {
+                        part,
+                        example,
+                        answer: html_text(&answer[1]),
+                    });
+                }
+            }
+        }
+        fixture
+    }
+
+    pub fn load(dir: &Path, year: u16, day: u8) -> Result<Option<Fixture>> {
+        let dir = fixture_dir(dir, year, day);
+        let Some(answers) = read_if_exists(&dir.join("answers.txt"))? else {
+            return Ok(None);
+        };
+        let mut fixture = Fixture::default();
+        while let Some(example) =
+            read_if_exists(&dir.join(format!("example{}.txt", fixture.examples.len() + 1)))?
+        {
+            fixture.examples.push(example);
+        }
+        for line in answers.lines().filter(|l| !l.is_empty()) {
+            let answer = parse_answer(line)
+                .filter(|a| a.example < fixture.examples.len())
+                .ok_or_else(|| anyhow!("Invalid answer in {}: {}", dir.display(), line))?;
+            fixture.answers.push(answer);
+        }
+        Ok(Some(fixture))
+    }
+
+    pub fn save(&self, dir: &Path, year: u16, day: u8) -> Result<()> {
+        let dir = fixture_dir(dir, year, day);
+        for (index, example) in self.examples.iter().enumerate() {
+            write_creating_dirs(&dir.join(format!("example{}.txt", index + 1)), example)?;
+        }
+        let answers: String = self
+            .answers
+            .iter()
+            .map(|a| format!("{}\t{}\t{}\n", a.part, a.example + 1, a.answer))
+            .collect();
+        write_creating_dirs(&dir.join("answers.txt"), &answers)
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&ExampleAnswer> {
+        self.answers.iter().find(|a| a.part == part)
+    }
+}
+
+pub fn fixture_dir(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{:02}", day))
+}
+
+/// Fixture stored with the sources, for the tests of a day
+#[cfg(test)]
+pub fn fixture(year: u16, day: u8) -> Fixture {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    Fixture::load(&dir, year, day)
+        .unwrap()
+        .unwrap_or_else(|| panic!("No fixture for {} day {}", year, day))
+}
+
+/// Descriptions of each part, part two is only visible once part one is solved
+fn articles(html: &str) -> Vec<&str> {
+    html.split("<article")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("</article>").map(|(article, _)| article))
+        .collect()
+}
+
+/// Text of an HTML fragment, without tags and entities
+fn html_text(html: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn parse_answer(line: &str) -> Option<ExampleAnswer> {
+    let mut split = line.splitn(3, '\t');
+    let part = Part::from_str(split.next()?).ok()?;
+    let example: usize = split.next()?.parse().ok()?;
+    let answer = split.next()?.to_string();
+    Some(ExampleAnswer {
+        part,
+        example: example.checked_sub(1)?,
+        answer,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE_PAGE: &str = "<html><body><main>
+<article class=\"day-desc\"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+2   5
+</code></pre>
+<p>Pairs are compared like <code>a &lt; b</code>:</p>
+<pre><code><em>3</em>   4
+</code></pre>
+<p>In the example above, this is <code><em>11</em></code>!</p>
+</article>
+<p>Your puzzle answer was <code>1834060</code>.</p>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>
+<p>So, for these example lists, the similarity score is <code><em>31</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>21607792</code>.</p>
+</main></body></html>";
+
+    #[test]
+    fn test_parse() {
+        let fixture = Fixture::parse(PUZZLE_PAGE);
+
+        assert_eq!(
+            vec![String::from("3   4\n4   3\n2   5"), String::from("3   4")],
+            fixture.examples
+        );
+        assert_eq!(
+            vec![
+                ExampleAnswer {
+                    part: Part::One,
+                    example: 0,
+                    answer: String::from("11")
+                },
+                ExampleAnswer {
+                    part: Part::Two,
+                    example: 0,
+                    answer: String::from("31")
+                }
+            ],
+            fixture.answers
+        );
+    }
+
+    #[test]
+    fn test_parse_second_part_example() {
+        let html = "<article><pre><code>a</code></pre><code><em>1</em></code></article>
+<article><pre><code>b &amp; c</code></pre><code><em>2</em></code></article>";
+
+        let fixture = Fixture::parse(html);
+
+        assert_eq!(vec!["a", "b & c"], fixture.examples);
+        assert_eq!(1, fixture.answer(Part::Two).unwrap().example);
+    }
+
+    #[test]
+    fn test_parse_without_examples() {
+        assert_eq!(Fixture::default(), Fixture::parse("<html>Not found</html>"));
+    }
+
+    #[test]
+    fn test_fixtures_of_registered_solvers() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        for solver in crate::registry().iter() {
+            let Some(fixture) = Fixture::load(&dir, solver.year(), solver.day()).unwrap() else {
+                continue;
+            };
+            for expected in &fixture.answers {
+                if let Some(answer) =
+                    solver.solve(expected.part, &fixture.examples[expected.example])
+                {
+                    assert_eq!(
+                        expected.answer,
+                        answer.unwrap(),
+                        "{} day {} part {}",
+                        solver.year(),
+                        solver.day(),
+                        expected.part
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_save_then_load() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let fixture = Fixture::parse(PUZZLE_PAGE);
+
+        assert_eq!(None, Fixture::load(dir, 2024, 1).unwrap());
+        fixture.save(dir, 2024, 1).unwrap();
+
+        assert_eq!(Some(fixture), Fixture::load(dir, 2024, 1).unwrap());
+        assert_eq!(
+            "1\t1\t11\n2\t1\t31\n",
+            std::fs::read_to_string(dir.join("2024").join("day01").join("answers.txt")).unwrap()
+        );
+    }
+}
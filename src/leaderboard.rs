@@ -0,0 +1,310 @@
+// Private leaderboard, as given by the JSON API of the website
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+
+use crate::cache::{read_if_exists, write_creating_dirs};
+use crate::client::{unlock_time, AocClient};
+
+/// The website asks to fetch a leaderboard at most every 15 minutes
+pub const REFRESH: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Missing for anonymous users
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// Stars by day then by part, both as strings like `"1"`
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    /// Unix time when the star was obtained
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Time taken to get the star of a part after the puzzle unlocked
+    pub fn star_time(&self, year: u16, day: u8, part: u8) -> Option<Duration> {
+        let star = self
+            .completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())?;
+        (UNIX_EPOCH + Duration::from_secs(star.get_star_ts))
+            .duration_since(unlock_time(year, day))
+            .ok()
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Leaderboard> {
+        serde_json::from_str(json)
+            .context("Invalid leaderboard, check the session is allowed to view it")
+    }
+
+    /// Reads a saved leaderboard JSON
+    pub fn load(path: &Path) -> Result<Leaderboard> {
+        let json =
+            read_if_exists(path)?.ok_or_else(|| anyhow!("No leaderboard {}", path.display()))?;
+        Leaderboard::parse(&json)
+    }
+
+    /// Reads the copy kept in `cache_dir`, downloaded again when older than `REFRESH`. The client
+    /// is only created to download it
+    pub async fn fetch(
+        client: impl FnOnce() -> Result<AocClient>,
+        cache_dir: &Path,
+        year: u16,
+        id: u64,
+    ) -> Result<Leaderboard> {
+        let path = cache_path(cache_dir, year, id);
+        let age = std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok());
+        match read_if_exists(&path)? {
+            Some(json) if age.is_some_and(|age| age < REFRESH) => {
+                log::info!("Using leaderboard {} fetched {:?} ago", path.display(), age);
+                Leaderboard::parse(&json)
+            }
+            _ => {
+                let json = client()?.download_leaderboard(year, id).await?;
+                let leaderboard = Leaderboard::parse(&json)?;
+                write_creating_dirs(&path, &json)?;
+                Ok(leaderboard)
+            }
+        }
+    }
+
+    /// Members by decreasing score, then stars
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.display_name().cmp(&b.display_name()))
+        });
+        members
+    }
+
+    /// Days on which at least one star was obtained
+    fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .members
+            .values()
+            .flat_map(|m| m.completion_day_level.keys())
+            .filter_map(|day| day.parse().ok())
+            .collect();
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    /// Standings followed by the time of each star since the unlock and between both parts
+    pub fn render(&self, year: u16) -> String {
+        let standings = self.standings();
+        let names: Vec<String> = standings.iter().map(|m| m.display_name()).collect();
+        let name_width = names
+            .iter()
+            .map(String::len)
+            .chain(["Name".len()])
+            .max()
+            .unwrap();
+
+        let mut output = String::from("Rank  Score  Stars  Name\n");
+        for (rank, (member, name)) in standings.iter().zip(&names).enumerate() {
+            output.push_str(&format!(
+                "{:>4}  {:>5}  {:>5}  {}\n",
+                rank + 1,
+                member.local_score,
+                member.stars,
+                name
+            ));
+        }
+
+        let rows: Vec<[String; 5]> = self
+            .days()
+            .into_iter()
+            .flat_map(|day| {
+                standings
+                    .iter()
+                    .zip(&names)
+                    .filter_map(move |(member, name)| {
+                        let part1 = member.star_time(year, day, 1)?;
+                        let part2 = member.star_time(year, day, 2);
+                        Some([
+                            day.to_string(),
+                            name.clone(),
+                            format_time(&part1),
+                            part2.map_or(String::from("-"), |t| format_time(&t)),
+                            part2
+                                .and_then(|t| t.checked_sub(part1))
+                                .map_or(String::from("-"), |t| format_time(&t)),
+                        ])
+                    })
+            })
+            .collect();
+        if rows.is_empty() {
+            return output;
+        }
+        let time_width = rows
+            .iter()
+            .flat_map(|row| row[2..].iter().map(String::len))
+            .chain(["Part 1".len()])
+            .max()
+            .unwrap();
+        output.push_str(&format!(
+            "\nDay  {:<name_width$}  {:>time_width$}  {:>time_width$}  {:>time_width$}\n",
+            "Name", "Part 1", "Part 2", "Delta"
+        ));
+        for [day, name, part1, part2, delta] in rows {
+            output.push_str(&format!(
+                "{:>3}  {:<name_width$}  {:>time_width$}  {:>time_width$}  {:>time_width$}\n",
+                day, name, part1, part2, delta
+            ));
+        }
+        output
+    }
+}
+
+/// Copy of a leaderboard, as `<cache_dir>/leaderboards/2024-1234.json`
+pub fn cache_path(cache_dir: &Path, year: u16, id: u64) -> PathBuf {
+    cache_dir
+        .join("leaderboards")
+        .join(format!("{}-{}.json", year, id))
+}
+
+/// Like "01:02:03", with the days when longer
+fn format_time(duration: &Duration) -> String {
+    let seconds = duration.as_secs();
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    match seconds / 86400 {
+        0 => time,
+        days => format!("{}d {}", days, time),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-12-01T05:00:00Z is 1733029200, day 2 unlocks 86400 later
+    const LEADERBOARD: &str = r#"{
+  "event": "2024",
+  "owner_id": 1,
+  "members": {
+    "1": {
+      "id": 1, "name": "Alice", "stars": 3, "local_score": 7, "global_score": 0,
+      "last_star_ts": 1733116000,
+      "completion_day_level": {
+        "1": {
+          "1": {"get_star_ts": 1733029700, "star_index": 1},
+          "2": {"get_star_ts": 1733030000, "star_index": 2}
+        },
+        "2": {"1": {"get_star_ts": 1733116000, "star_index": 5}}
+      }
+    },
+    "2": {
+      "id": 2, "name": null, "stars": 2, "local_score": 4, "global_score": 0,
+      "last_star_ts": 1733120000,
+      "completion_day_level": {
+        "1": {
+          "1": {"get_star_ts": 1733029600, "star_index": 0},
+          "2": {"get_star_ts": 1733120000, "star_index": 6}
+        }
+      }
+    },
+    "3": {
+      "id": 3, "name": "Bob", "stars": 0, "local_score": 0, "global_score": 0,
+      "last_star_ts": 0, "completion_day_level": {}
+    }
+  }
+}"#;
+
+    #[test]
+    fn test_standings() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+
+        let names: Vec<String> = leaderboard
+            .standings()
+            .iter()
+            .map(|m| m.display_name())
+            .collect();
+
+        assert_eq!(vec!["Alice", "(anonymous user #2)", "Bob"], names);
+    }
+
+    #[test]
+    fn test_star_time() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+        let alice = &leaderboard.members["1"];
+
+        assert_eq!(Some(Duration::from_secs(500)), alice.star_time(2024, 1, 1));
+        assert_eq!(Some(Duration::from_secs(400)), alice.star_time(2024, 2, 1));
+        assert_eq!(None, alice.star_time(2024, 2, 2));
+    }
+
+    #[test]
+    fn test_render() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+
+        assert_eq!(
+            "Rank  Score  Stars  Name
+   1      7      3  Alice
+   2      4      2  (anonymous user #2)
+   3      0      0  Bob
+
+Day  Name                      Part 1       Part 2        Delta
+  1  Alice                   00:08:20     00:13:20     00:05:00
+  1  (anonymous user #2)     00:06:40  1d 01:13:20  1d 01:06:40
+  2  Alice                   00:06:40            -            -
+",
+            leaderboard.render(2024)
+        );
+    }
+
+    #[test]
+    fn test_render_part2_before_part1() {
+        let json = LEADERBOARD.replace("1733030000", "1733029500");
+        let leaderboard = Leaderboard::parse(&json).unwrap();
+
+        assert!(
+            leaderboard
+                .render(2024)
+                .contains("  1  Alice                   00:08:20     00:05:00            -\n"),
+            "{}",
+            leaderboard.render(2024)
+        );
+    }
+
+    #[test]
+    fn test_invalid_leaderboard() {
+        assert!(Leaderboard::parse("<html>Log in</html>").is_err());
+    }
+}
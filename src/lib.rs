@@ -0,0 +1,31 @@
+// Solvers of the puzzles with their common toolkit, and the tooling around the website
+
+pub mod answer;
+pub mod answers;
+pub mod aoc_2023;
+pub mod aoc_2024;
+pub mod aoc_2025;
+pub mod bench;
+pub mod cache;
+pub mod client;
+pub mod common;
+pub mod examples;
+pub mod leaderboard;
+pub mod report;
+pub mod scaffold;
+pub mod session;
+pub mod solver;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+
+use solver::Registry;
+
+/// Every puzzle of every year
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    aoc_2023::register(&mut registry);
+    aoc_2024::register(&mut registry);
+    aoc_2025::register(&mut registry);
+    registry
+}
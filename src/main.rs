@@ -1,31 +1,349 @@
-use std::time::{Duration, Instant};
+use std::path::PathBuf;
+use std::time::Duration;
 
+use advent_of_code_2023::answer::Answer;
+use advent_of_code_2023::answers::AnswerStore;
+use advent_of_code_2023::bench;
+use advent_of_code_2023::cache::{read_if_exists, InputCache};
+use advent_of_code_2023::client::{AocClient, DEFAULT_BASE_URL, RETRIES};
+use advent_of_code_2023::examples::{fixture_dir, Fixture};
+use advent_of_code_2023::leaderboard::Leaderboard;
+use advent_of_code_2023::report::{self, Format, Outcome};
+use advent_of_code_2023::session::{self, Session};
+use advent_of_code_2023::solver::{Part, Registry, Solver};
+use advent_of_code_2023::submit::{self, Submitted};
+use advent_of_code_2023::verify::{self, Status};
+use advent_of_code_2023::{registry, scaffold, watch};
 use anyhow::{anyhow, Result};
-use clap::Parser;
-use reqwest::ClientBuilder;
-
-mod aoc_2023;
-mod aoc_2024;
-mod aoc_2025;
+use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about)]
 /// Learn Rust through AOC
 struct Args {
+    #[command(subcommand)]
+    command: Command,
+
     #[clap(short, long)]
-    /// AOC authentication
-    auth: String,
+    verbosity: Option<usize>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Download the inputs of one or several days and solve them
+    Run(RunArgs),
+    /// List the puzzles that can be solved
+    List,
+    /// Send an answer to the website, solving the puzzle when no answer is given
+    Submit(SubmitArgs),
+    /// Store the accepted answer of a part
+    Record(RecordArgs),
+    /// Check that solvers still find the accepted answers on cached inputs
+    Verify(VerifyArgs),
+    /// Time a solver part over many runs, optionally against a saved baseline
+    Bench(BenchArgs),
+    /// Generate the skeleton of a new day and register it
+    New(NewArgs),
+    /// Extract the examples and their answers from the puzzle description, as test fixtures
+    Examples(ExamplesArgs),
+    /// Show the standings and star times of a private leaderboard
+    Leaderboard(LeaderboardArgs),
+    /// Run the tests and the solver of a day again each time its source or input changes
+    Watch(WatchArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct RunArgs {
+    #[command(flatten)]
+    client: ClientArgs,
+
+    #[clap(short, long, required_unless_present = "all")]
+    /// Year to solve, every day of the year when no day is given
+    year: Option<u16>,
+
+    #[clap(short, long, requires = "year")]
+    /// Day to solve
+    day: Option<u8>,
+
+    #[clap(long, conflicts_with = "year")]
+    /// Solve every day of every year
+    all: bool,
+
+    #[clap(short, long, value_enum, default_value_t = Format::Text)]
+    /// How the results are written to stdout
+    format: Format,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct SubmitArgs {
+    #[command(flatten)]
+    client: ClientArgs,
 
     #[clap(short, long)]
-    /// Year to solve
+    /// Year of the puzzle
     year: u16,
 
     #[clap(short, long)]
-    /// Day to solve
+    /// Day of the puzzle
     day: u8,
 
     #[clap(short, long)]
-    verbosity: Option<usize>,
+    /// Part answered, 1 or 2
+    part: Part,
+
+    /// Answer to send, computed by the solver when missing
+    answer: Option<String>,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct RecordArgs {
+    #[clap(short, long)]
+    /// Year of the puzzle
+    year: u16,
+
+    #[clap(short, long)]
+    /// Day of the puzzle
+    day: u8,
+
+    #[clap(short, long)]
+    /// Part answered, 1 or 2
+    part: Part,
+
+    /// Accepted answer
+    answer: String,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct VerifyArgs {
+    #[clap(short, long)]
+    /// Only verify this year
+    year: Option<u16>,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct BenchArgs {
+    #[command(flatten)]
+    client: ClientArgs,
+
+    #[clap(short, long)]
+    /// Year of the puzzle
+    year: u16,
+
+    #[clap(short, long)]
+    /// Day of the puzzle
+    day: u8,
+
+    #[clap(short, long)]
+    /// Part to time, 1 or 2
+    part: Part,
+
+    #[clap(short, long, default_value_t = 100)]
+    /// Number of measured runs
+    iterations: usize,
+
+    #[clap(long, default_value_t = 3)]
+    /// Number of runs before measuring
+    warmup: usize,
+
+    #[clap(long)]
+    /// Save the statistics under this name
+    save_baseline: Option<String>,
+
+    #[clap(long)]
+    /// Compare the statistics to the baseline saved under this name
+    baseline: Option<String>,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct NewArgs {
+    #[clap(short, long)]
+    /// Year of the puzzle
+    year: u16,
+
+    #[clap(short, long)]
+    /// Day of the puzzle
+    day: u8,
+
+    #[clap(short, long, default_value = "TODO")]
+    /// Title of the puzzle, listed with the puzzles
+    title: String,
+
+    #[clap(short, long)]
+    /// File with the example input of the puzzle, used as test input
+    example: Option<PathBuf>,
+
+    #[clap(long, default_value = "fixtures")]
+    /// Directory of the extracted examples, whose first one is the test input when no file is given
+    fixtures_dir: PathBuf,
+
+    #[clap(long, default_value = "src")]
+    /// Source directory of the project
+    src: PathBuf,
+}
+
+#[derive(clap::Args, Debug)]
+struct ExamplesArgs {
+    #[command(flatten)]
+    client: ClientArgs,
+
+    #[clap(short, long)]
+    /// Year of the puzzle
+    year: u16,
+
+    #[clap(short, long)]
+    /// Day of the puzzle
+    day: u8,
+
+    #[clap(long)]
+    /// Saved copy of the puzzle page, read instead of downloading it
+    html: Option<PathBuf>,
+
+    #[clap(long, default_value = "fixtures")]
+    /// Directory where the fixtures are stored
+    fixtures_dir: PathBuf,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct LeaderboardArgs {
+    #[command(flatten)]
+    client: ClientArgs,
+
+    #[clap(short, long)]
+    /// Id of the private leaderboard, the number at the end of its URL
+    id: u64,
+
+    #[clap(short, long)]
+    /// Year of the event
+    year: u16,
+
+    #[clap(long)]
+    /// Saved leaderboard JSON, read instead of downloading it
+    file: Option<PathBuf>,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct WatchArgs {
+    #[command(flatten)]
+    client: ClientArgs,
+
+    #[clap(short, long)]
+    /// Year of the puzzle
+    year: u16,
+
+    #[clap(short, long)]
+    /// Day of the puzzle
+    day: u8,
+
+    #[clap(long, default_value = "src")]
+    /// Source directory of the project
+    src: PathBuf,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct ClientArgs {
+    #[clap(short, long, env = "AOC_SESSION", hide_env_values = true)]
+    /// AOC session cookie, otherwise read from the token file or the configuration
+    auth: Option<Session>,
+
+    #[clap(long, env = "AOC_SESSION_FILE")]
+    /// File containing the session cookie, instead of `token` in the configuration directory
+    session_file: Option<PathBuf>,
+
+    #[clap(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    /// Root URL of the AOC website, to use another server
+    base_url: String,
+
+    #[clap(long, env = "AOC_MIN_INTERVAL", default_value_t = 3.0)]
+    /// Minimum number of seconds between two requests to the website, also across runs
+    min_interval: f64,
+
+    #[clap(long, default_value_t = RETRIES)]
+    /// Number of new attempts after a timeout or a server error
+    retries: u32,
+}
+
+impl ClientArgs {
+    /// Client logging its requests in the cache directory
+    fn client(&self, cache: &CacheArgs) -> Result<AocClient> {
+        let config_dir = session::config_dir();
+        let session = session::find(
+            self.auth.as_ref(),
+            self.session_file.as_deref(),
+            config_dir.as_deref(),
+        )?
+        .ok_or_else(|| {
+            anyhow!(
+                "An AOC session is needed to access the website: use -a, AOC_SESSION, \
+                 --session-file or a token or config.toml file in {}",
+                config_dir.map_or(String::from("~/.config/aoc"), |d| d.display().to_string())
+            )
+        })?;
+        Ok(AocClient::new(&self.base_url, &session)?
+            .with_min_interval(Duration::from_secs_f64(self.min_interval.max(0.0)))
+            .with_retries(self.retries)
+            .with_request_log(cache.cache_dir.join("requests.log")))
+    }
+}
+
+#[derive(clap::Args, Debug)]
+struct InputArgs {
+    #[command(flatten)]
+    cache: CacheArgs,
+
+    #[clap(long)]
+    /// Only use cached inputs, never download
+    offline: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct CacheArgs {
+    #[clap(long, default_value = "inputs")]
+    /// Directory where downloaded inputs, submissions, accepted answers and the log of requests are kept
+    cache_dir: PathBuf,
+}
+
+impl InputArgs {
+    /// Reads the input from the cache, downloading it only when missing
+    async fn get(&self, client: &ClientArgs, year: u16, day: u8) -> Result<String> {
+        self.cache
+            .inputs()
+            .get_or_download(year, day, self.offline, || client.client(&self.cache))
+            .await
+    }
+}
+
+impl CacheArgs {
+    fn inputs(&self) -> InputCache {
+        InputCache::new(&self.cache_dir)
+    }
+
+    fn answers(&self) -> Result<AnswerStore> {
+        AnswerStore::load(self.cache_dir.join("answers.txt"))
+    }
 }
 
 #[tokio::main]
@@ -35,113 +353,268 @@ async fn main() -> Result<()> {
     let args = Args::parse();
     log::warn!("Starting AOC app with parameters {:?}", args);
 
-    let day = args.day;
-    let input = download_input(args.auth, args.year, day).await?;
+    let registry = registry();
+    match args.command {
+        Command::Run(run_args) => run(run_args, &registry).await,
+        Command::List => {
+            list(&registry);
+            Ok(())
+        }
+        Command::Submit(submit_args) => submit(submit_args, &registry).await,
+        Command::Record(record_args) => record_args.cache.answers()?.set(
+            record_args.year,
+            record_args.day,
+            record_args.part,
+            &record_args.answer,
+        ),
+        Command::Verify(verify_args) => verify(verify_args, &registry),
+        Command::Bench(bench_args) => bench(bench_args, &registry).await,
+        Command::New(new_args) => new_day(new_args),
+        Command::Examples(examples_args) => examples(examples_args).await,
+        Command::Leaderboard(leaderboard_args) => leaderboard(leaderboard_args).await,
+        Command::Watch(watch_args) => watch(watch_args).await,
+    }
+}
 
-    if args.year == 2023 {
-        let (part1, part2) = match args.day {
-            1 => (aoc_2023::day1::day1(&input), aoc_2023::day1::day1_2(&input)),
-            2 => (aoc_2023::day2::day2(&input), aoc_2023::day2::day2_2(&input)),
-            3 => (aoc_2023::day3::day3(&input), aoc_2023::day3::day3_2(&input)),
-            other => {
-                return Err(anyhow!("Cannot handle day {other}"));
-            }
-        };
-        log::info!("Result for day {} part 1 = {}", args.day, part1);
-        log::info!("Result for day {} part 2 = {}", args.day, part2);
-    } else if args.year == 2024 {
-        let (part1, part2) = match args.day {
-            1 => (aoc_2024::day1::day1(&input), aoc_2024::day1::day1_2(&input)),
-            2 => (aoc_2024::day2::day2(&input), aoc_2024::day2::day2_2(&input)),
-            3 => (aoc_2024::day3::day3(&input), aoc_2024::day3::day3(&input)),
-            4 => (aoc_2024::day4::day4(&input), aoc_2024::day4::day4(&input)),
-            5 => (aoc_2024::day5::day5(&input), aoc_2024::day5::day5_2(&input)),
-            6 => (
-                with_timer("day 6 part 1", &|| aoc_2024::day6::day6(&input)),
-                with_timer("day 6 part 2", &|| aoc_2024::day6::day6_2(&input)),
-            ),
-            7 => (
-                with_timer("day 7 part 1", &|| aoc_2024::day7::day7(&input)),
-                with_timer("day 7 part 2", &|| aoc_2024::day7::day7_2(&input)),
-            ),
-            8 => (
-                with_timer("day 8 part 1", &|| aoc_2024::day8::day8(&input)),
-                with_timer("day 8 part 2", &|| aoc_2024::day8::day8_2(&input)),
-            ),
-            9 => (
-                with_timer("day 9 part 1", &|| aoc_2024::day9::day9(&input)),
-                with_timer("day 9 part 2", &|| aoc_2024::day9::day9_2(&input)),
-            ),
-            10 => (
-                with_timer("day 10 part 1", &|| aoc_2024::day10::day10(&input)),
-                with_timer("day 10 part 2", &|| aoc_2024::day10::day10_2(&input)),
-            ),
-            12 => (
-                aoc_2024::day12::day12(&input),
-                aoc_2024::day12::day12_2(&input),
-            ),
-            13 => (
-                aoc_2024::day13::day13(&input),
-                aoc_2024::day13::day13_2(&input),
-            ),
-            other => {
-                return Err(anyhow!("Cannot handle day {other}"));
-            }
-        };
-        log::info!("Result 2024 for day {} part 1 = {}", args.day, part1);
-        log::info!("Result 2024 for day {} part 2 = {}", args.day, part2);
-    } else if args.year == 2025 {
-        let (part1, part2) = match args.day {
-            1 => (aoc_2025::day1::day1(&input), aoc_2025::day1::day1_2(&input)),
-            2 => (aoc_2025::day2::day2(&input), aoc_2025::day2::day2_2(&input)),
-            3 => (aoc_2025::day3::day3(&input), aoc_2025::day3::day3_2(&input)),
-            4 => (aoc_2025::day4::day4(&input), aoc_2025::day4::day4_2(&input)),
-            5 => (aoc_2025::day5::day5(&input), aoc_2025::day5::day5_2(&input)),
-            6 => (aoc_2025::day6::day6(&input), aoc_2025::day6::day6_2(&input)),
-            7 => (aoc_2025::day7::day7(&input), aoc_2025::day7::day7_2(&input)),
-            8 => (
-                aoc_2025::day8::day8(&input, 1000),
-                aoc_2025::day8::day8_2(&input),
-            ),
-            9 => (aoc_2025::day9::day9(&input), aoc_2025::day9::day9_2(&input)),
-            // 11 => (aoc_2025::day11::day11(&input), aoc_2025::day11::day11_2(&input)),
-            other => {
-                return Err(anyhow!("Cannot handle day {other}"));
-            }
-        };
-        log::info!("Result 2025 for day {} part 1 = {}", args.day, part1);
-        log::info!("Result 2025 for day {} part 2 = {}", args.day, part2);
+async fn run(args: RunArgs, registry: &Registry) -> Result<()> {
+    let solvers: Vec<&dyn Solver> = match (args.year, args.day) {
+        (Some(year), Some(day)) => vec![registry.find(year, day)?],
+        (Some(year), None) => registry.iter().filter(|s| s.year() == year).collect(),
+        _ => registry.iter().collect(),
+    };
+    if solvers.is_empty() {
+        return Err(anyhow!("No puzzle to solve"));
+    }
+
+    let results = report::solve_all(
+        &solvers,
+        &args.input.cache.inputs(),
+        args.input.offline,
+        || args.client.client(&args.input.cache),
+    )
+    .await?;
+
+    print!("{}", report::format(&results, args.format));
+    let failed = results
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::Failed(_)))
+        .count();
+    if failed > 0 {
+        return Err(anyhow!("{} parts failed", failed));
+    }
+    Ok(())
+}
+
+async fn submit(args: SubmitArgs, registry: &Registry) -> Result<()> {
+    let name = format!("{} day {} part {}", args.year, args.day, args.part);
+    let answer = match &args.answer {
+        Some(answer) => Answer::from(answer.as_str()),
+        None => {
+            let solver = registry.find(args.year, args.day)?;
+            let input = args.input.get(&args.client, args.year, args.day).await?;
+            solver.answer(args.part, &input)?
+        }
+    };
+
+    let submitted = submit::submit(
+        || args.client.client(&args.input.cache),
+        &args.input.cache.inputs(),
+        &mut args.input.cache.answers()?,
+        args.year,
+        args.day,
+        args.part,
+        &answer,
+    )
+    .await?;
+    match submitted {
+        Submitted::OutOfBounds(bound) => println!(
+            "{} is not sent for {}, {} was already rejected: {}",
+            answer, name, bound.answer, bound.outcome
+        ),
+        Submitted::AlreadySent(previous) => println!(
+            "{} was already submitted for {}, not sending it again: {}",
+            answer, name, previous.outcome
+        ),
+        Submitted::Sent(outcome) => println!("{} = {}: {}", name, answer, outcome),
     }
+    Ok(())
+}
 
+fn verify(args: VerifyArgs, registry: &Registry) -> Result<()> {
+    let verifications = verify::verify(
+        registry,
+        &args.cache.inputs(),
+        &args.cache.answers()?,
+        args.year,
+    )?;
+    let mut checked = 0;
+    let mut mismatches = 0;
+    for verification in verifications {
+        match verification.status {
+            Status::NoAnswer => continue,
+            Status::Ok => checked += 1,
+            Status::Mismatch { .. } | Status::Failed(_) => {
+                checked += 1;
+                mismatches += 1;
+            }
+            Status::NotImplemented | Status::NoInput => {}
+        }
+        println!(
+            "{} day {:>2} part {}: {}",
+            verification.year, verification.day, verification.part, verification.status
+        );
+    }
+    println!("{} answers checked, {} mismatches", checked, mismatches);
+    if mismatches > 0 {
+        return Err(anyhow!("{} answers are not found anymore", mismatches));
+    }
     Ok(())
 }
 
-const TIMEOUT: Duration = Duration::from_secs(10);
+async fn bench(args: BenchArgs, registry: &Registry) -> Result<()> {
+    let name = format!("{} day {} part {}", args.year, args.day, args.part);
+    let solver = registry.find(args.year, args.day)?;
+    if !solver.is_implemented(args.part) {
+        return Err(anyhow!("{} is not implemented", name));
+    }
+    let input = args.input.get(&args.client, args.year, args.day).await?;
+
+    let stats = bench::bench_part(solver, args.part, &input, args.warmup, args.iterations)?;
+    println!("{}", name);
+    print!("{}", stats);
 
-async fn download_input(auth_cookie: String, year: u16, day: u8) -> Result<String> {
-    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
+    let cache = args.input.cache.inputs();
+    let baseline_path =
+        |baseline: &str| bench::baseline_path(&cache, args.year, args.day, args.part, baseline);
+    if let Some(baseline) = &args.baseline {
+        let saved = bench::load_baseline(&baseline_path(baseline))?;
+        println!("Compared to {}: {}", baseline, stats.compare(&saved));
+    }
+    if let Some(baseline) = &args.save_baseline {
+        let path = baseline_path(baseline);
+        bench::save_baseline(&path, &stats)?;
+        println!("Saved baseline {} to {}", baseline, path.display());
+    }
+    Ok(())
+}
 
-    let client = ClientBuilder::new().timeout(TIMEOUT).build().unwrap();
+fn new_day(args: NewArgs) -> Result<()> {
+    let example = match &args.example {
+        Some(path) => {
+            Some(read_if_exists(path)?.ok_or_else(|| anyhow!("No example {}", path.display()))?)
+        }
+        None => Fixture::load(&args.fixtures_dir, args.year, args.day)?
+            .and_then(|fixture| fixture.examples.into_iter().next()),
+    };
+    let written = scaffold::new_day(
+        &args.src,
+        args.year,
+        args.day,
+        &args.title,
+        example.as_deref(),
+    )?;
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
 
-    let request = client
-        .get(url)
-        .header("Cookie", format!("session={auth}", auth = auth_cookie));
-    log::info!("Sending request {:?}", request);
-    let res = request.send().await?;
+async fn examples(args: ExamplesArgs) -> Result<()> {
+    let html = match &args.html {
+        Some(path) => read_if_exists(path)?.ok_or_else(|| anyhow!("No page {}", path.display()))?,
+        None => {
+            args.client
+                .client(&args.cache)?
+                .download_puzzle(args.year, args.day)
+                .await?
+        }
+    };
+    let fixture = Fixture::parse(&html);
+    if fixture.examples.is_empty() {
+        return Err(anyhow!(
+            "No example in the description of {} day {}",
+            args.year,
+            args.day
+        ));
+    }
+    fixture.save(&args.fixtures_dir, args.year, args.day)?;
 
-    let body = res.text().await?;
+    println!(
+        "{} examples saved in {}",
+        fixture.examples.len(),
+        fixture_dir(&args.fixtures_dir, args.year, args.day).display()
+    );
+    for part in Part::all() {
+        match fixture.answer(part) {
+            Some(answer) => println!(
+                "part {}: {} for example {}",
+                part,
+                answer.answer,
+                answer.example + 1
+            ),
+            None => println!("part {}: no answer found", part),
+        }
+    }
+    Ok(())
+}
 
-    log::info!("response: {}", body);
-    Ok(body)
+async fn leaderboard(args: LeaderboardArgs) -> Result<()> {
+    let leaderboard = match &args.file {
+        Some(path) => Leaderboard::load(path)?,
+        None => {
+            Leaderboard::fetch(
+                || args.client.client(&args.cache),
+                &args.cache.cache_dir,
+                args.year,
+                args.id,
+            )
+            .await?
+        }
+    };
+    print!("{}", leaderboard.render(args.year));
+    Ok(())
 }
 
-fn with_timer<T>(name: &str, function: &dyn Fn() -> T) -> T {
-    let now = Instant::now();
-    let result = function();
+async fn watch(args: WatchArgs) -> Result<()> {
+    // Downloaded once here, the runs below only use the cache
+    args.input.get(&args.client, args.year, args.day).await?;
+    let paths = watch::watched_paths(&args.src, &args.input.cache.inputs(), args.year, args.day);
     println!(
-        "Execution time of {}, {}ms",
-        name,
-        now.elapsed().as_millis()
+        "Watching {}, stop with Ctrl-C",
+        paths
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<String>>()
+            .join(" and ")
     );
-    result
+    watch::watch(
+        &paths,
+        &args.input.cache.cache_dir,
+        args.year,
+        args.day,
+        |line| println!("{}", line),
+    )
+    .await
+}
+
+fn list(registry: &Registry) {
+    for solver in registry.iter() {
+        let parts: Vec<String> = Part::all()
+            .iter()
+            .filter(|part| solver.is_implemented(**part))
+            .map(Part::to_string)
+            .collect();
+        let parts = if parts.is_empty() {
+            String::from("not implemented")
+        } else {
+            format!("parts {}", parts.join(", "))
+        };
+        println!(
+            "{} day {:>2}: {} ({})",
+            solver.year(),
+            solver.day(),
+            solver.title(),
+            parts
+        );
+    }
 }
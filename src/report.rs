@@ -0,0 +1,379 @@
+// Results of solver runs and their summary table
+
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::answer::Answer;
+use crate::cache::InputCache;
+use crate::client::{AocClient, InputError};
+use crate::solver::{Part, Solver};
+
+/// How results are written to stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// Flat view of a result, for scripts
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: &'a str,
+    answer: Option<String>,
+    duration_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+    NotImplemented,
+    /// The solver returned an error, with its causes
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub duration: Duration,
+}
+
+impl PartResult {
+    /// Solves the part, timing the solver
+    pub fn solve(solver: &dyn Solver, part: Part, input: &str) -> PartResult {
+        let now = Instant::now();
+        let outcome = match solver.solve(part, input) {
+            Some(Ok(answer)) => Outcome::Solved(answer),
+            Some(Err(error)) => Outcome::Failed(format!("{:#}", error)),
+            None => Outcome::NotImplemented,
+        };
+        PartResult {
+            year: solver.year(),
+            day: solver.day(),
+            part,
+            outcome,
+            duration: now.elapsed(),
+        }
+    }
+
+    pub fn not_implemented(solver: &dyn Solver, part: Part) -> PartResult {
+        PartResult {
+            year: solver.year(),
+            day: solver.day(),
+            part,
+            outcome: Outcome::NotImplemented,
+            duration: Duration::ZERO,
+        }
+    }
+
+    fn record(&self) -> Record<'_> {
+        let (status, answer, error) = match &self.outcome {
+            Outcome::Solved(answer) => ("solved", Some(answer.to_string()), None),
+            Outcome::NotImplemented => ("not_implemented", None, None),
+            Outcome::Failed(error) => ("failed", None, Some(error.as_str())),
+        };
+        Record {
+            year: self.year,
+            day: self.day,
+            part: self.part.number(),
+            status,
+            answer,
+            duration_ms: self.duration.as_nanos() as f64 / 1e6,
+            error,
+        }
+    }
+
+    fn answer(&self) -> String {
+        match &self.outcome {
+            Outcome::Solved(answer) => answer.to_string(),
+            Outcome::NotImplemented => String::from("not implemented"),
+            Outcome::Failed(_) => String::from("failed"),
+        }
+    }
+}
+
+/// Solves every part of the days, downloading the missing inputs. With several days, those without
+/// an input yet, not cached when offline or not unlocked, are skipped
+pub async fn solve_all(
+    solvers: &[&dyn Solver],
+    cache: &InputCache,
+    offline: bool,
+    client: impl Fn() -> Result<AocClient>,
+) -> Result<Vec<PartResult>> {
+    let mut results = Vec::new();
+    for solver in solvers {
+        let (year, day) = (solver.year(), solver.day());
+        if !Part::all().iter().any(|part| solver.is_implemented(*part)) {
+            results.extend(Part::all().map(|part| PartResult::not_implemented(*solver, part)));
+            continue;
+        }
+        let input = match cache.get_or_download(year, day, offline, &client).await {
+            Ok(input) => input,
+            Err(error) if solvers.len() > 1 && (offline || is_not_unlocked(&error)) => {
+                log::warn!("Skipping {} day {}: {}", year, day, error);
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
+
+        for part in Part::all() {
+            let result = PartResult::solve(*solver, part, &input);
+            match &result.outcome {
+                Outcome::Solved(answer) => {
+                    log::info!("Result for {} day {} part {} = {}", year, day, part, answer)
+                }
+                Outcome::NotImplemented => {
+                    log::warn!("{} day {} part {} is not implemented", year, day, part)
+                }
+                Outcome::Failed(error) => {
+                    log::error!("{} day {} part {} failed: {}", year, day, part, error)
+                }
+            }
+            results.push(result);
+        }
+    }
+    Ok(results)
+}
+
+fn is_not_unlocked(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<InputError>(),
+        Some(InputError::NotUnlocked { .. })
+    )
+}
+
+/// Milliseconds with microseconds precision
+pub fn format_duration(duration: &Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+pub fn format(results: &[PartResult], format: Format) -> String {
+    match format {
+        Format::Text => table(results),
+        Format::Json => json(results),
+        Format::Csv => csv(results),
+    }
+}
+
+/// Array of records, one per part
+pub fn json(results: &[PartResult]) -> String {
+    let records: Vec<Record> = results.iter().map(PartResult::record).collect();
+    serde_json::to_string_pretty(&records).unwrap() + "\n"
+}
+
+/// One record per line after a header, answers quoted when needed
+pub fn csv(results: &[PartResult]) -> String {
+    let mut csv = String::from("year,day,part,status,answer,duration_ms\n");
+    for record in results.iter().map(PartResult::record) {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{:.3}\n",
+            record.year,
+            record.day,
+            record.part,
+            record.status,
+            csv_field(record.answer.as_deref().unwrap_or("")),
+            record.duration_ms
+        ));
+    }
+    csv
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Table of the results with one line per part, followed by the totals
+pub fn table(results: &[PartResult]) -> String {
+    let answer_width = results
+        .iter()
+        .map(|r| r.answer().len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap();
+    let times: Vec<String> = results
+        .iter()
+        .map(|r| match r.outcome {
+            Outcome::Solved(_) | Outcome::Failed(_) => format_duration(&r.duration),
+            Outcome::NotImplemented => String::from("-"),
+        })
+        .collect();
+    let total_duration: Duration = results.iter().map(|r| r.duration).sum();
+    let total_time = format_duration(&total_duration);
+    let time_width = times
+        .iter()
+        .map(String::len)
+        .chain([total_time.len(), "Time".len()])
+        .max()
+        .unwrap();
+
+    let mut table = format!(
+        "Year  Day  Part  {:<answer_width$}  {:>time_width$}\n",
+        "Answer", "Time"
+    );
+    for (result, time) in results.iter().zip(times) {
+        table.push_str(&format!(
+            "{}  {:>3}  {:>4}  {:<answer_width$}  {:>time_width$}\n",
+            result.year,
+            result.day,
+            result.part,
+            result.answer(),
+            time
+        ));
+    }
+    let solved = results
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::Solved(_)))
+        .count();
+    let failed = results
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::Failed(_)))
+        .count();
+    let total_label = match failed {
+        0 => format!("Total: {} parts solved", solved),
+        _ => format!("Total: {} parts solved, {} failed", solved, failed),
+    };
+    table.push_str(&format!(
+        "{:<width$}  {:>time_width$}\n",
+        total_label,
+        total_time,
+        width = "Year  Day  Part  ".len() + answer_width
+    ));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, part: Part, outcome: Outcome, micros: u64) -> PartResult {
+        PartResult {
+            year: 2024,
+            day,
+            part,
+            outcome,
+            duration: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("0.153 ms", format_duration(&Duration::from_micros(153)));
+        assert_eq!(
+            "1234.500 ms",
+            format_duration(&Duration::from_micros(1234500))
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let results = vec![
+            result(1, Part::One, Outcome::Solved(Answer::Integer(11)), 120),
+            result(7, Part::Two, Outcome::NotImplemented, 0),
+        ];
+
+        assert_eq!(
+            "[
+  {
+    \"year\": 2024,
+    \"day\": 1,
+    \"part\": 1,
+    \"status\": \"solved\",
+    \"answer\": \"11\",
+    \"duration_ms\": 0.12
+  },
+  {
+    \"year\": 2024,
+    \"day\": 7,
+    \"part\": 2,
+    \"status\": \"not_implemented\",
+    \"answer\": null,
+    \"duration_ms\": 0.0
+  }
+]
+",
+            json(&results)
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        let results = vec![
+            result(1, Part::One, Outcome::Solved(Answer::Integer(11)), 120),
+            result(1, Part::Two, Outcome::Solved(Answer::from("a,\"b\"")), 1500),
+            result(7, Part::Two, Outcome::NotImplemented, 0),
+        ];
+
+        assert_eq!(
+            "year,day,part,status,answer,duration_ms
+2024,1,1,solved,11,0.120
+2024,1,2,solved,\"a,\"\"b\"\"\",1.500
+2024,7,2,not_implemented,,0.000
+",
+            csv(&results)
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let results = vec![
+            result(1, Part::One, Outcome::Solved(Answer::Integer(11)), 120),
+            result(1, Part::Two, Outcome::Solved(Answer::Integer(31)), 1500),
+            result(7, Part::Two, Outcome::NotImplemented, 0),
+        ];
+
+        assert_eq!(
+            "Year  Day  Part  Answer               Time
+2024    1     1  11               0.120 ms
+2024    1     2  31               1.500 ms
+2024    7     2  not implemented         -
+Total: 2 parts solved             1.620 ms
+",
+            table(&results)
+        );
+    }
+
+    #[test]
+    fn test_failed() {
+        let results = vec![
+            result(1, Part::One, Outcome::Solved(Answer::Integer(11)), 120),
+            result(
+                1,
+                Part::Two,
+                Outcome::Failed(String::from("line 3, column 5: expected a number")),
+                40,
+            ),
+        ];
+
+        assert_eq!(
+            "Year  Day  Part  Answer      Time
+2024    1     1  11      0.120 ms
+2024    1     2  failed  0.040 ms
+Total: 1 parts solved, 1 failed  0.160 ms
+",
+            table(&results)
+        );
+        assert!(json(&results).contains(
+            "\"status\": \"failed\",
+    \"answer\": null,
+    \"duration_ms\": 0.04,
+    \"error\": \"line 3, column 5: expected a number\"
+"
+        ));
+        assert!(!json(&results[..1]).contains("error"));
+    }
+}
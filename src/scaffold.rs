@@ -0,0 +1,299 @@
+// Generation of the skeleton of a new day, registered in its year module
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+
+use crate::cache::{read_if_exists, write_creating_dirs};
+
+/// Creates `aoc_<year>/day<day>.rs` under `src` and registers it, returns the files written
+pub fn new_day(
+    src: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+    example: Option<&str>,
+) -> Result<Vec<PathBuf>> {
+    let year_dir = src.join(format!("aoc_{}", year));
+    let day_path = year_dir.join(format!("day{}.rs", day));
+    if day_path.exists() {
+        return Err(anyhow!("{} already exists", day_path.display()));
+    }
+
+    let mod_path = year_dir.join("mod.rs");
+    let mut written = vec![day_path.clone(), mod_path.clone()];
+    let year_module = match read_if_exists(&mod_path)? {
+        Some(year_module) => year_module,
+        None => {
+            let lib_path = src.join("lib.rs");
+            let lib = read_if_exists(&lib_path)?
+                .ok_or_else(|| anyhow!("No {} to add year {} to", lib_path.display(), year))?;
+            write_creating_dirs(&lib_path, &register_year(&lib, year)?)?;
+            written.push(lib_path);
+            String::from(
+                "use crate::solver::{Puzzle, Registry};\n\npub fn register(registry: &mut Registry) {\n}\n",
+            )
+        }
+    };
+
+    write_creating_dirs(&day_path, &day_module(day, example))?;
+    write_creating_dirs(&mod_path, &register_day(&year_module, year, day, title)?)?;
+    Ok(written)
+}
+
+/// Source of a day with both parts left to solve
+pub fn day_module(day: u8, example: Option<&str>) -> String {
+    let example = example.unwrap_or("").trim_end();
+    format!(
+        r#"use crate::answer::Answer;
+use anyhow::Result;
+#[cfg(not(test))]
+use log::info;
+#[cfg(test)]
+use std::println as info;
+
+pub fn day{day}(input: &str) -> Result<Answer> {{
+    let lines: Vec<&str> = input.lines().collect();
+    info!("{{}} lines", lines.len());
+    Ok(Answer::NotImplemented)
+}}
+
+pub fn day{day}_2(input: &str) -> Result<Answer> {{
+    let lines: Vec<&str> = input.lines().collect();
+    info!("{{}} lines", lines.len());
+    Ok(Answer::NotImplemented)
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const TEST_INPUT: &str = "{example}";
+
+    #[test]
+    #[ignore = "the answer of the example is not filled in yet"]
+    fn test_day{day}() {{
+        let result = day{day}(TEST_INPUT).unwrap();
+
+        assert_eq!("TODO", result);
+    }}
+
+    #[test]
+    #[ignore = "the answer of the example is not filled in yet"]
+    fn test_day{day}_2() {{
+        let result = day{day}_2(TEST_INPUT).unwrap();
+
+        assert_eq!("TODO", result);
+    }}
+}}
+"#,
+        day = day,
+        example = example.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+/// Adds the module declaration and the registration of the day to a year module
+pub fn register_day(year_module: &str, year: u16, day: u8, title: &str) -> Result<String> {
+    let module = format!("day{}", day);
+    let mut lines: Vec<String> = year_module.lines().map(String::from).collect();
+
+    let day_modules: Vec<(usize, &str, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let (visibility, name) = line.strip_suffix(';')?.split_once("mod ")?;
+            let declared = name.starts_with("day") && !visibility.starts_with("//");
+            declared.then_some((index, visibility, name))
+        })
+        .collect();
+    if day_modules.iter().any(|(_, _, name)| *name == module) {
+        return Err(anyhow!("Module {} is already declared", module));
+    }
+    let visibility = day_modules
+        .last()
+        .map_or("pub ", |(_, visibility, _)| visibility)
+        .to_string();
+    // Same order as rustfmt, which sorts module names as strings
+    let module_index = match day_modules.iter().find(|(_, _, name)| **name > *module) {
+        Some((index, _, _)) => *index,
+        None => match day_modules.last() {
+            Some((index, _, _)) => index + 1,
+            None => {
+                let after_imports = lines
+                    .iter()
+                    .rposition(|l| l.starts_with("use "))
+                    .map_or(0, |i| i + 1);
+                lines.insert(after_imports, String::new());
+                after_imports + 1
+            }
+        },
+    };
+    lines.insert(module_index, format!("{}mod {};", visibility, module));
+
+    let register_start = lines
+        .iter()
+        .position(|l| l.contains("fn register("))
+        .ok_or_else(|| anyhow!("No register function for {}", year))?;
+    let register_end = register_start
+        + lines[register_start..]
+            .iter()
+            .position(|l| l == "}")
+            .ok_or_else(|| anyhow!("Unterminated register function for {}", year))?;
+    lines.insert(
+        register_end,
+        format!(
+            "    registry.register(\n        Puzzle::new({}, {}, {:?})\n            .with_part1({}::{})\n            .with_part2({}::{}_2),\n    );",
+            year, day, title, module, module, module, module
+        ),
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Declares the year module in `lib.rs` and adds it to the registry
+pub fn register_year(lib: &str, year: u16) -> Result<String> {
+    let module = format!("aoc_{}", year);
+    let mut lines: Vec<String> = lib.lines().map(String::from).collect();
+
+    let last_year = lines
+        .iter()
+        .rposition(|l| l.starts_with("pub mod aoc_"))
+        .ok_or_else(|| anyhow!("No year module declared"))?;
+    let module_index = lines
+        .iter()
+        .position(|l| {
+            l.starts_with("pub mod ") && l.as_str() > format!("pub mod {};", module).as_str()
+        })
+        .unwrap_or(last_year + 1);
+    lines.insert(module_index, format!("pub mod {};", module));
+
+    let last_register = lines
+        .iter()
+        .rposition(|l| l.trim_start().starts_with("aoc_") && l.contains("::register("))
+        .ok_or_else(|| anyhow!("No year registered"))?;
+    lines.insert(
+        last_register + 1,
+        format!("    {}::register(&mut registry);", module),
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_MODULE: &str = "use crate::solver::{Puzzle, Registry};
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+// pub mod day4;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(
+        Puzzle::new(2024, 1, \"First\")
+            .with_part1(day1::day1)
+            .with_part2(day1::day1_2),
+    );
+}
+";
+
+    #[test]
+    fn test_day_module_with_example() {
+        let module = day_module(7, Some("a \"b\"\nc\n"));
+
+        assert!(module.contains("pub fn day7(input: &str) -> Result<Answer> {"));
+        assert!(module.contains("pub fn day7_2(input: &str) -> Result<Answer> {"));
+        assert!(module.contains("const TEST_INPUT: &str = \"a \\\"b\\\"\nc\";"));
+        assert!(module.contains("let result = day7_2(TEST_INPUT).unwrap();"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let module = register_day(YEAR_MODULE, 2024, 3, "Third").unwrap();
+
+        assert_eq!(
+            "use crate::solver::{Puzzle, Registry};
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+// pub mod day4;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(
+        Puzzle::new(2024, 1, \"First\")
+            .with_part1(day1::day1)
+            .with_part2(day1::day1_2),
+    );
+    registry.register(
+        Puzzle::new(2024, 3, \"Third\")
+            .with_part1(day3::day3)
+            .with_part2(day3::day3_2),
+    );
+}
+",
+            module
+        );
+    }
+
+    #[test]
+    fn test_register_day_sorted_as_rustfmt() {
+        let module = register_day(YEAR_MODULE, 2024, 11, "Eleventh").unwrap();
+
+        assert!(module.contains("mod day10;\npub mod day11;\npub mod day2;"));
+    }
+
+    #[test]
+    fn test_register_day_twice() {
+        assert!(register_day(YEAR_MODULE, 2024, 10, "Tenth").is_err());
+    }
+
+    #[test]
+    fn test_register_first_day() {
+        let module = register_day(
+            "use crate::solver::{Puzzle, Registry};\n\npub fn register(registry: &mut Registry) {\n}\n",
+            2026,
+            1,
+            "New",
+        )
+        .unwrap();
+
+        assert!(module.starts_with(
+            "use crate::solver::{Puzzle, Registry};\n\npub mod day1;\n\npub fn register("
+        ));
+        assert!(module.contains("Puzzle::new(2026, 1, \"New\")"));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib = "pub mod answers;
+pub mod aoc_2024;
+pub mod cache;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    aoc_2024::register(&mut registry);
+    registry
+}
+";
+
+        assert_eq!(
+            "pub mod answers;
+pub mod aoc_2024;
+pub mod aoc_2026;
+pub mod cache;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    aoc_2024::register(&mut registry);
+    aoc_2026::register(&mut registry);
+    registry
+}
+",
+            register_year(lib, 2026).unwrap()
+        );
+    }
+}
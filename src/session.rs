@@ -0,0 +1,161 @@
+// AOC session cookie, looked up without having to type it on the command line
+
+use std::convert::Infallible;
+use std::fmt::{Debug, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+
+use crate::cache::read_if_exists;
+
+/// Session token, never printed in logs
+#[derive(Clone, PartialEq, Eq)]
+pub struct Session(String);
+
+impl Session {
+    pub fn new(token: &str) -> Session {
+        Session(token.trim().to_string())
+    }
+
+    pub fn token(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for Session {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Session(<redacted>)")
+    }
+}
+
+impl FromStr for Session {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Session::new(s))
+    }
+}
+
+/// Content of `config.toml`, other keys are ignored
+#[derive(Deserialize)]
+struct Config {
+    session: Option<String>,
+}
+
+/// `$XDG_CONFIG_HOME/aoc`, or `~/.config/aoc`
+pub fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("aoc"))
+}
+
+/// Finds the session, in order: the given one (flag or `AOC_SESSION`), the given token file,
+/// then the `token` file and the `session` key of `config.toml` in the configuration directory
+pub fn find(
+    given: Option<&Session>,
+    token_file: Option<&Path>,
+    config_dir: Option<&Path>,
+) -> Result<Option<Session>> {
+    if let Some(session) = given {
+        return Ok(Some(session.clone()));
+    }
+    if let Some(path) = token_file {
+        let token = read_if_exists(path)?
+            .ok_or_else(|| anyhow!("No session token file {}", path.display()))?;
+        return Ok(non_empty(&token));
+    }
+    let Some(dir) = config_dir else {
+        return Ok(None);
+    };
+    if let Some(token) = read_if_exists(&dir.join("token"))? {
+        return Ok(non_empty(&token));
+    }
+    let config_path = dir.join("config.toml");
+    match read_if_exists(&config_path)? {
+        Some(config) => {
+            let config: Config = toml::from_str(&config)
+                .with_context(|| format!("Invalid configuration {}", config_path.display()))?;
+            Ok(config.session.as_deref().and_then(non_empty))
+        }
+        None => Ok(None),
+    }
+}
+
+fn non_empty(token: &str) -> Option<Session> {
+    let session = Session::new(token);
+    (!session.token().is_empty()).then_some(session)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let session = Session::new("53616c7465645f5f");
+
+        assert_eq!("Session(<redacted>)", format!("{:?}", session));
+        assert_eq!("53616c7465645f5f", session.token());
+    }
+
+    #[test]
+    fn test_given_session_first() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        std::fs::write(dir.join("token"), "from-file").unwrap();
+
+        let session = find(Some(&Session::new("given")), None, Some(dir)).unwrap();
+
+        assert_eq!(Some(Session::new("given")), session);
+    }
+
+    #[test]
+    fn test_token_file() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        std::fs::write(dir.join("other"), "from-other\n").unwrap();
+        std::fs::write(dir.join("token"), "from-token\n").unwrap();
+
+        assert_eq!(
+            Some(Session::new("from-other")),
+            find(None, Some(&dir.join("other")), Some(dir)).unwrap()
+        );
+        assert_eq!(
+            Some(Session::new("from-token")),
+            find(None, None, Some(dir)).unwrap()
+        );
+        assert!(find(None, Some(&dir.join("missing")), Some(dir)).is_err());
+    }
+
+    #[test]
+    fn test_config_file() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        std::fs::write(
+            dir.join("config.toml"),
+            "# AOC\nsession = \"from-config\"\nleaderboard = 1234\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some(Session::new("from-config")),
+            find(None, None, Some(dir)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_no_session() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+
+        assert_eq!(None, find(None, None, Some(dir)).unwrap());
+        assert_eq!(None, find(None, None, None).unwrap());
+
+        std::fs::write(dir.join("config.toml"), "session = [").unwrap();
+        assert!(find(None, None, Some(dir)).is_err());
+    }
+}
@@ -0,0 +1,262 @@
+// Common interface to run any puzzle without knowing its module
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::answer::Answer;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("Invalid part {}, expected 1 or 2", other)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.number(), f)
+    }
+}
+
+/// A puzzle of a given day that can be solved from its input
+pub trait Solver {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &str;
+
+    /// Returns `None` when the part is not implemented
+    fn part1(&self, input: &str) -> Option<Result<Answer>>;
+
+    /// Returns `None` when the part is not implemented
+    fn part2(&self, input: &str) -> Option<Result<Answer>>;
+
+    fn solve(&self, part: Part, input: &str) -> Option<Result<Answer>> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    fn is_implemented(&self, part: Part) -> bool;
+
+    /// Answer of the part, failing when it is not implemented
+    fn answer(&self, part: Part, input: &str) -> Result<Answer> {
+        let name = format!("{} day {} part {}", self.year(), self.day(), part);
+        self.solve(part, input)
+            .ok_or_else(|| anyhow!("{} is not implemented", name))?
+            .with_context(|| format!("{} failed", name))
+    }
+}
+
+type PartFn = fn(&str) -> Result<Answer>;
+
+/// Solver built from the `dayN` functions of a puzzle module
+pub struct Puzzle {
+    year: u16,
+    day: u8,
+    title: &'static str,
+    part1: Option<PartFn>,
+    part2: Option<PartFn>,
+}
+
+impl Puzzle {
+    pub fn new(year: u16, day: u8, title: &'static str) -> Puzzle {
+        Puzzle {
+            year,
+            day,
+            title,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub fn with_part1(mut self, part1: PartFn) -> Puzzle {
+        self.part1 = Some(part1);
+        self
+    }
+
+    pub fn with_part2(mut self, part2: PartFn) -> Puzzle {
+        self.part2 = Some(part2);
+        self
+    }
+}
+
+impl Solver for Puzzle {
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn title(&self) -> &str {
+        self.title
+    }
+
+    fn part1(&self, input: &str) -> Option<Result<Answer>> {
+        self.part1.and_then(|part| implemented(part(input)))
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        self.part2.and_then(|part| implemented(part(input)))
+    }
+
+    fn is_implemented(&self, part: Part) -> bool {
+        match part {
+            Part::One => self.part1.is_some(),
+            Part::Two => self.part2.is_some(),
+        }
+    }
+}
+
+/// `None` for the parts answering `Answer::NotImplemented`
+fn implemented(result: Result<Answer>) -> Option<Result<Answer>> {
+    match result {
+        Ok(Answer::NotImplemented) => None,
+        result => Some(result),
+    }
+}
+
+/// All the known solvers, sorted by year then day
+#[derive(Default)]
+pub struct Registry {
+    solvers: Vec<Box<dyn Solver>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry {
+            solvers: Vec::new(),
+        }
+    }
+
+    /// Adds a solver, replacing any solver already registered for the same day
+    pub fn register(&mut self, solver: impl Solver + 'static) {
+        let key = (solver.year(), solver.day());
+        match self
+            .solvers
+            .binary_search_by_key(&key, |s| (s.year(), s.day()))
+        {
+            Ok(index) => self.solvers[index] = Box::new(solver),
+            Err(index) => self.solvers.insert(index, Box::new(solver)),
+        }
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn Solver> {
+        self.solvers
+            .iter()
+            .find(|s| s.year() == year && s.day() == day)
+            .map(Box::as_ref)
+    }
+
+    /// Same as `get`, failing for a day without solver
+    pub fn find(&self, year: u16, day: u8) -> Result<&dyn Solver> {
+        self.get(year, day)
+            .ok_or_else(|| anyhow!("Cannot handle {} day {}", year, day))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Solver> {
+        self.solvers.iter().map(Box::as_ref)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn length(input: &str) -> Result<Answer> {
+        Ok(input.len().into())
+    }
+
+    fn lines(input: &str) -> Result<Answer> {
+        Ok(input.lines().count().into())
+    }
+
+    fn in_progress(_: &str) -> Result<Answer> {
+        Ok(Answer::NotImplemented)
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!(Ok(Part::One), Part::from_str("1"));
+        assert_eq!(Ok(Part::Two), Part::from_str("2"));
+        assert!(Part::from_str("3").is_err());
+    }
+
+    #[test]
+    fn test_puzzle_parts() {
+        let puzzle = Puzzle::new(2024, 1, "Test").with_part1(length);
+
+        assert_eq!(
+            Some(Answer::Integer(3)),
+            puzzle.solve(Part::One, "abc").map(Result::unwrap)
+        );
+        assert!(puzzle.solve(Part::Two, "abc").is_none());
+        assert!(puzzle.is_implemented(Part::One));
+        assert!(!puzzle.is_implemented(Part::Two));
+    }
+
+    #[test]
+    fn test_part_in_progress() {
+        let puzzle = Puzzle::new(2024, 1, "Test").with_part1(in_progress);
+
+        assert!(puzzle.solve(Part::One, "abc").is_none());
+    }
+
+    #[test]
+    fn test_registry_sorted() {
+        let mut registry = Registry::new();
+        registry.register(Puzzle::new(2025, 1, "Third"));
+        registry.register(Puzzle::new(2024, 2, "Second"));
+        registry.register(Puzzle::new(2024, 1, "First"));
+
+        let titles: Vec<&str> = registry.iter().map(|s| s.title()).collect();
+
+        assert_eq!(vec!["First", "Second", "Third"], titles);
+    }
+
+    #[test]
+    fn test_registry_get() {
+        let mut registry = Registry::new();
+        registry.register(Puzzle::new(2024, 1, "First").with_part1(length));
+        registry.register(Puzzle::new(2024, 1, "Replaced").with_part1(lines));
+
+        let solver = registry.get(2024, 1).unwrap();
+
+        assert_eq!("Replaced", solver.title());
+        assert_eq!(
+            Some(Answer::Integer(2)),
+            solver.part1("a\nb").map(Result::unwrap)
+        );
+        assert!(registry.get(2024, 2).is_none());
+    }
+}
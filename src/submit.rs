@@ -0,0 +1,374 @@
+// Answers submission: classify the website response and remember what was already tried
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+
+use crate::answer::Answer;
+use crate::answers::AnswerStore;
+use crate::cache::{read_if_exists, write_creating_dirs, InputCache};
+use crate::client::AocClient;
+use crate::solver::Part;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was sent too recently, with the time left to wait
+    RateLimited(Duration),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl SubmitOutcome {
+    /// Reads the outcome from the HTML page answered to a submission
+    pub fn parse(html: &str) -> SubmitOutcome {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                SubmitOutcome::TooHigh
+            } else if text.contains("your answer is too low") {
+                SubmitOutcome::TooLow
+            } else {
+                SubmitOutcome::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            SubmitOutcome::RateLimited(parse_wait(&text).unwrap_or(Duration::ZERO))
+        } else if text.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::AlreadySolved
+        } else {
+            SubmitOutcome::Unknown(text)
+        }
+    }
+
+    /// Whether the answer was actually checked, so it must not be sent again
+    pub fn is_evaluated(&self) -> bool {
+        self.code().is_some()
+    }
+
+    fn code(&self) -> Option<&'static str> {
+        match self {
+            SubmitOutcome::Correct => Some("correct"),
+            SubmitOutcome::TooHigh => Some("too_high"),
+            SubmitOutcome::TooLow => Some("too_low"),
+            SubmitOutcome::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+
+    fn from_code(code: &str) -> Option<SubmitOutcome> {
+        match code {
+            "correct" => Some(SubmitOutcome::Correct),
+            "too_high" => Some(SubmitOutcome::TooHigh),
+            "too_low" => Some(SubmitOutcome::TooLow),
+            "wrong" => Some(SubmitOutcome::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmitOutcome::TooHigh => write!(f, "Wrong answer: too high"),
+            SubmitOutcome::TooLow => write!(f, "Wrong answer: too low"),
+            SubmitOutcome::Wrong => write!(f, "Wrong answer"),
+            SubmitOutcome::RateLimited(wait) => write!(
+                f,
+                "An answer was submitted too recently, wait {}",
+                format_wait(wait)
+            ),
+            SubmitOutcome::AlreadySolved => write!(f, "This part is already solved"),
+            SubmitOutcome::Unknown(text) => write!(f, "Unexpected response: {}", text),
+        }
+    }
+}
+
+/// Text of the `<article>` holding the message, without its tags
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article>")
+        .map(|(_, rest)| rest.split_once("</article>").map_or(rest, |(a, _)| a))
+        .unwrap_or(html);
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let text = tags.replace_all(article, "");
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Parses "You have 1m 7s left to wait"
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|token| {
+            let (value, seconds) = if let Some(hours) = token.strip_suffix('h') {
+                (hours, 3600)
+            } else if let Some(minutes) = token.strip_suffix('m') {
+                (minutes, 60)
+            } else {
+                (token.strip_suffix('s')?, 1)
+            };
+            value.parse::<u64>().ok().map(|value| value * seconds)
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+fn format_wait(wait: &Duration) -> String {
+    let seconds = wait.as_secs();
+    if seconds >= 60 {
+        format!("{}m {}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: Part,
+    pub answer: String,
+    pub outcome: SubmitOutcome,
+}
+
+/// Evaluated submissions of a day, stored one per line as `<part>\t<outcome>\t<answer>`
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn load(path: PathBuf) -> Result<SubmissionLog> {
+        let submissions = read_if_exists(&path)?
+            .unwrap_or_default()
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|line| {
+                parse_submission(line)
+                    .ok_or_else(|| anyhow!("Invalid submission in {}: {}", path.display(), line))
+            })
+            .collect::<Result<Vec<Submission>>>()?;
+        Ok(SubmissionLog { path, submissions })
+    }
+
+    pub fn find(&self, part: Part, answer: &str) -> Option<&Submission> {
+        self.submissions
+            .iter()
+            .find(|s| s.part == part && s.answer == answer)
+    }
+
+    /// Submission already showing the answer is wrong: a lower one too high or a higher one too low
+    pub fn bound(&self, part: Part, answer: &Answer) -> Option<&Submission> {
+        self.submissions
+            .iter()
+            .filter(|s| s.part == part)
+            .find(|s| {
+                let order = answer.partial_cmp(&Answer::from(s.answer.as_str()));
+                match s.outcome {
+                    SubmitOutcome::TooHigh => matches!(order, Some(Ordering::Greater)),
+                    SubmitOutcome::TooLow => matches!(order, Some(Ordering::Less)),
+                    _ => false,
+                }
+            })
+    }
+
+    /// Stores the submission if it was evaluated, ignores it otherwise
+    pub fn record(&mut self, part: Part, answer: &str, outcome: &SubmitOutcome) -> Result<()> {
+        if !outcome.is_evaluated() {
+            return Ok(());
+        }
+        self.submissions.push(Submission {
+            part,
+            answer: answer.to_string(),
+            outcome: outcome.clone(),
+        });
+        let content: String = self
+            .submissions
+            .iter()
+            .map(|s| format!("{}\t{}\t{}\n", s.part, s.outcome.code().unwrap(), s.answer))
+            .collect();
+        write_creating_dirs(&self.path, &content)
+    }
+}
+
+/// What became of an answer given to `submit`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submitted {
+    /// Not sent, this rejected answer already shows it is too high or too low
+    OutOfBounds(Submission),
+    /// Not sent again, it was already evaluated
+    AlreadySent(Submission),
+    Sent(SubmitOutcome),
+}
+
+/// Sends the answer unless the previous submissions of the day already evaluate it, and stores it
+/// with the accepted answers when it is correct. The client is only created to send it
+pub async fn submit(
+    client: impl FnOnce() -> Result<AocClient>,
+    cache: &InputCache,
+    answers: &mut AnswerStore,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Submitted> {
+    let mut submissions = SubmissionLog::load(cache.day_file(year, day, "submissions"))?;
+    if let Some(bound) = submissions.bound(part, answer) {
+        return Ok(Submitted::OutOfBounds(bound.clone()));
+    }
+    let answer = answer.to_string();
+    if let Some(previous) = submissions.find(part, &answer) {
+        return Ok(Submitted::AlreadySent(previous.clone()));
+    }
+
+    let response = client()?.submit_answer(year, day, part, &answer).await?;
+    let outcome = SubmitOutcome::parse(&response);
+    submissions.record(part, &answer, &outcome)?;
+    if outcome == SubmitOutcome::Correct {
+        answers.set(year, day, part, &answer)?;
+    }
+    Ok(Submitted::Sent(outcome))
+}
+
+fn parse_submission(line: &str) -> Option<Submission> {
+    let mut split = line.splitn(3, '\t');
+    let part = Part::from_str(split.next()?).ok()?;
+    let outcome = SubmitOutcome::from_code(split.next()?)?;
+    let answer = split.next()?.to_string();
+    Some(Submission {
+        part,
+        answer,
+        outcome,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_correct() {
+        let html = page("That's the right answer!  You are <em>one gold star</em> closer to finding the Chief Historian. <a href=\"/2024/day/1#part2\">[Continue to Part Two]</a>");
+
+        assert_eq!(SubmitOutcome::Correct, SubmitOutcome::parse(&html));
+    }
+
+    #[test]
+    fn test_parse_too_high() {
+        let html = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a>");
+
+        assert_eq!(SubmitOutcome::TooHigh, SubmitOutcome::parse(&html));
+    }
+
+    #[test]
+    fn test_parse_too_low() {
+        let html = page("That's not the right answer; your answer is too low.  Please wait one minute before trying again.");
+
+        assert_eq!(SubmitOutcome::TooLow, SubmitOutcome::parse(&html));
+    }
+
+    #[test]
+    fn test_parse_wrong() {
+        let html = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+
+        assert_eq!(SubmitOutcome::Wrong, SubmitOutcome::parse(&html));
+    }
+
+    #[test]
+    fn test_parse_rate_limited() {
+        let html = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 7s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a>");
+
+        assert_eq!(
+            SubmitOutcome::RateLimited(Duration::from_secs(67)),
+            SubmitOutcome::parse(&html)
+        );
+    }
+
+    #[test]
+    fn test_parse_rate_limited_seconds() {
+        let html = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.");
+
+        assert_eq!(
+            SubmitOutcome::RateLimited(Duration::from_secs(37)),
+            SubmitOutcome::parse(&html)
+        );
+    }
+
+    #[test]
+    fn test_parse_already_solved() {
+        let html = page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a>");
+
+        assert_eq!(SubmitOutcome::AlreadySolved, SubmitOutcome::parse(&html));
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        assert_eq!(
+            SubmitOutcome::Unknown(String::from("Something else")),
+            SubmitOutcome::parse(&page("Something <b>else</b>"))
+        );
+    }
+
+    #[test]
+    fn test_log_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day01.submissions");
+        let mut log = SubmissionLog::load(path.clone()).unwrap();
+
+        log.record(Part::One, "42", &SubmitOutcome::TooLow).unwrap();
+        log.record(Part::One, "43", &SubmitOutcome::RateLimited(Duration::ZERO))
+            .unwrap();
+        log.record(Part::Two, "a b", &SubmitOutcome::Correct)
+            .unwrap();
+
+        let log = SubmissionLog::load(path).unwrap();
+        assert_eq!(
+            SubmitOutcome::TooLow,
+            log.find(Part::One, "42").unwrap().outcome
+        );
+        assert!(log.find(Part::One, "43").is_none());
+        assert_eq!(
+            SubmitOutcome::Correct,
+            log.find(Part::Two, "a b").unwrap().outcome
+        );
+    }
+
+    #[test]
+    fn test_bound() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day01.submissions");
+        let mut log = SubmissionLog::load(path).unwrap();
+        log.record(Part::One, "100", &SubmitOutcome::TooHigh)
+            .unwrap();
+        log.record(Part::One, "20", &SubmitOutcome::TooLow).unwrap();
+        log.record(Part::One, "50", &SubmitOutcome::Wrong).unwrap();
+
+        let bound = |answer: &str| {
+            log.bound(Part::One, &Answer::from(answer))
+                .map(|s| s.answer.as_str())
+        };
+
+        assert_eq!(Some("100"), bound("120"));
+        assert_eq!(Some("20"), bound("7"));
+        assert_eq!(None, bound("60"));
+        assert_eq!(None, bound("a"));
+        assert_eq!(Some("100"), bound(&u128::MAX.to_string()));
+        assert_eq!(None, log.bound(Part::Two, &Answer::from(120)));
+    }
+}
@@ -0,0 +1,179 @@
+// Regression check: solvers must still find the accepted answers on their cached inputs
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use anyhow::Result;
+
+use crate::answer::Answer;
+use crate::answers::AnswerStore;
+use crate::cache::InputCache;
+use crate::solver::{Part, Registry};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Mismatch {
+        expected: Answer,
+        actual: Answer,
+    },
+    /// The solver returned an error, with its causes
+    Failed(String),
+    NotImplemented,
+    NoAnswer,
+    NoInput,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Mismatch { expected, actual } => {
+                write!(f, "MISMATCH expected {} but got {}", expected, actual)?;
+                match actual.partial_cmp(expected) {
+                    Some(Ordering::Greater) => write!(f, ", too high"),
+                    Some(Ordering::Less) => write!(f, ", too low"),
+                    _ => Ok(()),
+                }
+            }
+            Status::Failed(error) => write!(f, "FAILED {}", error),
+            Status::NotImplemented => write!(f, "not implemented"),
+            Status::NoAnswer => write!(f, "no accepted answer"),
+            Status::NoInput => write!(f, "no cached input"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+}
+
+/// Runs every registered solver of `year` (all years when `None`) that has an accepted answer
+pub fn verify(
+    registry: &Registry,
+    cache: &InputCache,
+    answers: &AnswerStore,
+    year: Option<u16>,
+) -> Result<Vec<Verification>> {
+    let mut verifications = Vec::new();
+    for solver in registry
+        .iter()
+        .filter(|s| year.is_none_or(|y| y == s.year()))
+    {
+        let input = cache.read(solver.year(), solver.day())?;
+        for part in Part::all() {
+            let status = match (answers.get(solver.year(), solver.day(), part), &input) {
+                (None, _) => Status::NoAnswer,
+                (Some(_), None) => Status::NoInput,
+                (Some(expected), Some(input)) => match solver.solve(part, input) {
+                    None => Status::NotImplemented,
+                    Some(Err(error)) => Status::Failed(format!("{:#}", error)),
+                    Some(Ok(actual)) if actual == expected => Status::Ok,
+                    Some(Ok(actual)) => Status::Mismatch {
+                        expected: Answer::from(expected),
+                        actual,
+                    },
+                },
+            };
+            verifications.push(Verification {
+                year: solver.year(),
+                day: solver.day(),
+                part,
+                status,
+            });
+        }
+    }
+    Ok(verifications)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::Puzzle;
+
+    fn length(input: &str) -> Result<Answer> {
+        Ok(input.len().into())
+    }
+
+    fn lines(input: &str) -> Result<Answer> {
+        Ok(input.lines().count().into())
+    }
+
+    fn failing(_: &str) -> Result<Answer> {
+        Err(anyhow::anyhow!("no solution"))
+    }
+
+    #[test]
+    fn test_verify() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let cache = InputCache::new(dir);
+        cache.write(2024, 1, "a\nb").unwrap();
+        cache.write(2024, 3, "c").unwrap();
+        let mut answers = AnswerStore::load(dir.join("answers.txt")).unwrap();
+        answers.set(2024, 1, Part::One, "3").unwrap();
+        answers.set(2024, 1, Part::Two, "3").unwrap();
+        answers.set(2024, 2, Part::One, "1").unwrap();
+        answers.set(2024, 3, Part::One, "1").unwrap();
+        answers.set(2025, 1, Part::Two, "1").unwrap();
+        let mut registry = Registry::new();
+        registry.register(
+            Puzzle::new(2024, 1, "Cached")
+                .with_part1(length)
+                .with_part2(lines),
+        );
+        registry.register(Puzzle::new(2024, 2, "Not cached").with_part1(length));
+        registry.register(Puzzle::new(2024, 3, "Failing").with_part1(failing));
+        registry.register(Puzzle::new(2025, 1, "Other year").with_part1(length));
+
+        let statuses: Vec<(u8, Part, Status)> = verify(&registry, &cache, &answers, Some(2024))
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.day, v.part, v.status))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (1, Part::One, Status::Ok),
+                (
+                    1,
+                    Part::Two,
+                    Status::Mismatch {
+                        expected: Answer::Integer(3),
+                        actual: Answer::Integer(2)
+                    }
+                ),
+                (2, Part::One, Status::NoInput),
+                (2, Part::Two, Status::NoAnswer),
+                (3, Part::One, Status::Failed(String::from("no solution"))),
+                (3, Part::Two, Status::NoAnswer),
+            ],
+            statuses
+        );
+    }
+
+    #[test]
+    fn test_mismatch_hint() {
+        let mismatch = |expected: &str, actual: &str| Status::Mismatch {
+            expected: Answer::from(expected),
+            actual: Answer::from(actual),
+        };
+
+        assert_eq!(
+            "MISMATCH expected 3 but got 12, too high",
+            mismatch("3", "12").to_string()
+        );
+        assert_eq!(
+            "MISMATCH expected 30 but got 4, too low",
+            mismatch("30", "4").to_string()
+        );
+        assert_eq!(
+            "MISMATCH expected ab but got 4",
+            mismatch("ab", "4").to_string()
+        );
+    }
+}
@@ -0,0 +1,241 @@
+// Summaries of the runs made each time the files of a day change
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::cache::InputCache;
+
+const POLL: Duration = Duration::from_millis(500);
+
+/// Outcome of `cargo test`, read from its output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestSummary {
+    /// False when the tests could not be built
+    pub compiled: bool,
+    pub passed: usize,
+    pub failed: Vec<String>,
+}
+
+impl TestSummary {
+    pub fn parse(output: &str) -> TestSummary {
+        let mut summary = TestSummary {
+            compiled: false,
+            passed: 0,
+            failed: Vec::new(),
+        };
+        for line in output.lines() {
+            if let Some(result) = line.strip_prefix("test result: ") {
+                summary.compiled = true;
+                summary.passed += result
+                    .split("; ")
+                    .find_map(|count| count.split_once(" passed"))
+                    .and_then(|(count, _)| count.rsplit(' ').next()?.parse::<usize>().ok())
+                    .unwrap_or(0);
+            } else if let Some(test) = line
+                .strip_prefix("test ")
+                .and_then(|l| l.strip_suffix(" ... FAILED"))
+            {
+                summary.failed.push(test.to_string());
+            }
+        }
+        summary
+    }
+
+    pub fn describe(&self) -> String {
+        if !self.compiled {
+            String::from("build failed")
+        } else if self.failed.is_empty() {
+            format!("{} passed", self.passed)
+        } else {
+            format!(
+                "{} passed, {} FAILED: {}",
+                self.passed,
+                self.failed.len(),
+                self.failed.join(", ")
+            )
+        }
+    }
+}
+
+/// Fields of the JSON records written by `run --format json` used here
+#[derive(Deserialize)]
+struct Record {
+    part: u8,
+    status: String,
+    answer: Option<String>,
+}
+
+/// Answer of each part, or its status when it has none
+pub fn parse_answers(json: &str) -> Result<BTreeMap<u8, String>> {
+    let records: Vec<Record> = serde_json::from_str(json).context("Invalid run output")?;
+    Ok(records
+        .into_iter()
+        .map(|r| (r.part, r.answer.unwrap_or(r.status.replace('_', " "))))
+        .collect())
+}
+
+/// Like "part 1: 12 (was 11), part 2: 31"
+pub fn answer_diff(previous: &BTreeMap<u8, String>, current: &BTreeMap<u8, String>) -> String {
+    current
+        .iter()
+        .map(|(part, answer)| match previous.get(part) {
+            Some(before) if before != answer => {
+                format!("part {}: {} (was {})", part, answer, before)
+            }
+            _ => format!("part {}: {}", part, answer),
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Last modification of each file, `None` for the missing ones
+pub fn modification_times(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Source of the day and its cached input
+pub fn watched_paths(src: &Path, cache: &InputCache, year: u16, day: u8) -> Vec<PathBuf> {
+    vec![
+        src.join(format!("aoc_{}", year))
+            .join(format!("day{}.rs", day)),
+        cache.path(year, day),
+    ]
+}
+
+/// Each time one of the paths changes, runs the tests of the day with cargo then the solver on the
+/// cached input when they build. Never returns unless cargo cannot be run, each run is described
+/// to `report` like "#2 tests: 2 passed | part 1: 12 (was 11), part 2: 31"
+pub async fn watch(
+    paths: &[PathBuf],
+    cache_dir: &Path,
+    year: u16,
+    day: u8,
+    mut report: impl FnMut(String),
+) -> Result<()> {
+    let test_filter = format!("aoc_{}::day{}::", year, day);
+    let (year, day) = (year.to_string(), day.to_string());
+    let cache_dir = cache_dir.to_string_lossy().to_string();
+
+    let mut answers = BTreeMap::new();
+    let mut last_times = None;
+    let mut run_count = 0;
+    loop {
+        let times = modification_times(paths);
+        if last_times.as_ref() == Some(&times) {
+            tokio::time::sleep(POLL).await;
+            continue;
+        }
+        last_times = Some(times);
+        run_count += 1;
+
+        let tests = cargo(&["test", &test_filter])?;
+        let tests = TestSummary::parse(&format!(
+            "{}{}",
+            String::from_utf8_lossy(&tests.stdout),
+            String::from_utf8_lossy(&tests.stderr)
+        ));
+        let mut line = format!("#{} tests: {}", run_count, tests.describe());
+        if tests.compiled {
+            let run = cargo(&[
+                "run",
+                "--quiet",
+                "--",
+                "run",
+                "-y",
+                &year,
+                "-d",
+                &day,
+                "--offline",
+                "--format",
+                "json",
+                "--cache-dir",
+                &cache_dir,
+            ])?;
+            match parse_answers(&String::from_utf8_lossy(&run.stdout)) {
+                Ok(current) => {
+                    line.push_str(&format!(" | {}", answer_diff(&answers, &current)));
+                    answers = current;
+                }
+                Err(_) => line.push_str(" | run failed"),
+            }
+        }
+        report(line);
+    }
+}
+
+fn cargo(args: &[&str]) -> Result<Output> {
+    Command::new("cargo")
+        .args(args)
+        .output()
+        .with_context(|| format!("Cannot run cargo {}", args.join(" ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tests() {
+        let output = "
+running 2 tests
+test aoc_2024::day1::tests::test_day1 ... ok
+test aoc_2024::day1::tests::test_day1_2 ... FAILED
+
+failures:
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 128 filtered out; finished in 0.00s
+";
+
+        assert_eq!(
+            TestSummary {
+                compiled: true,
+                passed: 1,
+                failed: vec![String::from("aoc_2024::day1::tests::test_day1_2")],
+            },
+            TestSummary::parse(output)
+        );
+        assert_eq!(
+            "1 passed, 1 FAILED: aoc_2024::day1::tests::test_day1_2",
+            TestSummary::parse(output).describe()
+        );
+    }
+
+    #[test]
+    fn test_parse_build_failure() {
+        let output =
+            "error[E0308]: mismatched types\nerror: could not compile `advent-of-code-2023`";
+
+        assert_eq!("build failed", TestSummary::parse(output).describe());
+    }
+
+    #[test]
+    fn test_answers_diff() {
+        let previous = parse_answers(
+            r#"[{"year": 2024, "day": 1, "part": 1, "status": "solved", "answer": "11", "duration_ms": 0.1},
+                {"year": 2024, "day": 1, "part": 2, "status": "not_implemented", "answer": null, "duration_ms": 0.0}]"#,
+        )
+        .unwrap();
+        let current = parse_answers(
+            r#"[{"year": 2024, "day": 1, "part": 1, "status": "solved", "answer": "11", "duration_ms": 0.1},
+                {"year": 2024, "day": 1, "part": 2, "status": "solved", "answer": "31", "duration_ms": 0.2}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            "part 1: 11, part 2: not implemented",
+            answer_diff(&BTreeMap::new(), &previous)
+        );
+        assert_eq!(
+            "part 1: 11, part 2: 31 (was not implemented)",
+            answer_diff(&previous, &current)
+        );
+    }
+}
@@ -0,0 +1,378 @@
+mod common;
+
+use common::{run_app, stderr, stdout, temp_dir, MockServer};
+
+const INPUT_2024_1: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+#[test]
+fn run_downloads_solves_and_reports() {
+    let server = MockServer::start();
+    server.route("GET", "/2024/day/1/input", 200, INPUT_2024_1);
+    let temp = temp_dir("run-download");
+    let cache_dir = temp.path();
+
+    let output = run_app(&[
+        "run",
+        "-a",
+        "test-session",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "--base-url",
+        server.url(),
+        "--cache-dir",
+        cache_dir.to_str().unwrap(),
+    ]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let logs = stderr(&output);
+    assert!(
+        logs.contains("Result for 2024 day 1 part 1 = 11"),
+        "{}",
+        logs
+    );
+    assert!(
+        logs.contains("Result for 2024 day 1 part 2 = 31"),
+        "{}",
+        logs
+    );
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!(Some("session=test-session"), requests[0].header("Cookie"));
+    assert_eq!(
+        INPUT_2024_1,
+        std::fs::read_to_string(cache_dir.join("2024").join("day01.txt")).unwrap()
+    );
+}
+
+#[test]
+fn run_uses_cached_input() {
+    let server = MockServer::start();
+    let temp = temp_dir("run-cached");
+    let cache_dir = temp.path();
+    std::fs::create_dir_all(cache_dir.join("2024")).unwrap();
+    std::fs::write(cache_dir.join("2024").join("day01.txt"), INPUT_2024_1).unwrap();
+
+    let output = run_app(&[
+        "run",
+        "-a",
+        "test-session",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "--base-url",
+        server.url(),
+        "--cache-dir",
+        cache_dir.to_str().unwrap(),
+    ]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Result for 2024 day 1 part 1 = 11"));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn run_year_prints_timing_table() {
+    let server = MockServer::start();
+    let temp = temp_dir("run-year");
+    let cache_dir = temp.path();
+    std::fs::create_dir_all(cache_dir.join("2024")).unwrap();
+    std::fs::write(cache_dir.join("2024").join("day01.txt"), INPUT_2024_1).unwrap();
+
+    let output = run_app(&[
+        "run",
+        "-a",
+        "test-session",
+        "-y",
+        "2024",
+        "--offline",
+        "--base-url",
+        server.url(),
+        "--cache-dir",
+        cache_dir.to_str().unwrap(),
+    ]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let table = stdout(&output);
+    assert!(table.starts_with("Year  Day  Part  Answer"), "{}", table);
+    assert!(table.contains("2024    1     1  11"), "{}", table);
+    assert!(!table.contains("2024   12"), "{}", table);
+    assert!(
+        stderr(&output).contains("Skipping 2024 day 12"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(table.contains("Total: 2 parts solved"), "{}", table);
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn bench_saves_and_compares_baseline() {
+    let temp = temp_dir("bench");
+    let cache_dir = temp.path();
+    std::fs::create_dir_all(cache_dir.join("2024")).unwrap();
+    std::fs::write(cache_dir.join("2024").join("day01.txt"), INPUT_2024_1).unwrap();
+    let bench = |extra: &[&str]| {
+        let mut args = vec![
+            "bench",
+            "-a",
+            "test-session",
+            "-y",
+            "2024",
+            "-d",
+            "1",
+            "-p",
+            "2",
+            "-i",
+            "5",
+            "--offline",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+        ];
+        args.extend_from_slice(extra);
+        run_app(&args)
+    };
+
+    let first = bench(&["--save-baseline", "before"]);
+    assert!(first.status.success(), "{}", stderr(&first));
+    assert!(
+        stdout(&first).contains("iterations 5"),
+        "{}",
+        stdout(&first)
+    );
+    assert!(cache_dir
+        .join("2024")
+        .join("day01.part2.before.bench")
+        .exists());
+
+    let second = bench(&["--baseline", "before"]);
+    assert!(second.status.success(), "{}", stderr(&second));
+    assert!(
+        stdout(&second).contains("Compared to before: mean "),
+        "{}",
+        stdout(&second)
+    );
+
+    let missing = bench(&["--baseline", "other"]);
+    assert!(!missing.status.success());
+}
+
+#[test]
+fn new_generates_and_registers_a_day() {
+    let temp = temp_dir("new");
+    let src = temp.path();
+    std::fs::write(
+        src.join("lib.rs"),
+        "pub mod aoc_2025;\npub mod cache;\n\npub fn registry() -> Registry {\n    let mut registry = Registry::new();\n    aoc_2025::register(&mut registry);\n    registry\n}\n",
+    )
+    .unwrap();
+    std::fs::write(src.join("example.txt"), "1 2\n3 4\n").unwrap();
+
+    let output = run_app(&[
+        "new",
+        "-y",
+        "2026",
+        "-d",
+        "1",
+        "-t",
+        "First",
+        "-e",
+        src.join("example.txt").to_str().unwrap(),
+        "--src",
+        src.to_str().unwrap(),
+    ]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let day = std::fs::read_to_string(src.join("aoc_2026").join("day1.rs")).unwrap();
+    assert!(
+        day.contains("pub fn day1_2(input: &str) -> Result<Answer> {"),
+        "{}",
+        day
+    );
+    assert!(
+        day.contains("const TEST_INPUT: &str = \"1 2\n3 4\";"),
+        "{}",
+        day
+    );
+    // The answers of the example are to fill in, until then the tests must not fail
+    assert_eq!(2, day.matches("#[ignore = ").count(), "{}", day);
+    let year = std::fs::read_to_string(src.join("aoc_2026").join("mod.rs")).unwrap();
+    assert!(year.contains("pub mod day1;"), "{}", year);
+    assert!(year.contains("Puzzle::new(2026, 1, \"First\")"), "{}", year);
+    let lib = std::fs::read_to_string(src.join("lib.rs")).unwrap();
+    assert!(
+        lib.contains("pub mod aoc_2025;\npub mod aoc_2026;\n"),
+        "{}",
+        lib
+    );
+    assert!(
+        lib.contains("aoc_2026::register(&mut registry);"),
+        "{}",
+        lib
+    );
+
+    let again = run_app(&[
+        "new",
+        "-y",
+        "2026",
+        "-d",
+        "1",
+        "--src",
+        src.to_str().unwrap(),
+    ]);
+    assert!(!again.status.success());
+}
+
+#[test]
+fn run_writes_json_and_csv_records() {
+    let temp = temp_dir("run-format");
+    let cache_dir = temp.path();
+    std::fs::create_dir_all(cache_dir.join("2024")).unwrap();
+    std::fs::write(cache_dir.join("2024").join("day01.txt"), INPUT_2024_1).unwrap();
+    let run = |format: &str| {
+        let output = run_app(&[
+            "run",
+            "-y",
+            "2024",
+            "-d",
+            "1",
+            "--offline",
+            "--format",
+            format,
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+        ]);
+        assert!(output.status.success(), "{}", stderr(&output));
+        stdout(&output)
+    };
+
+    let json = run("json");
+    assert!(json.starts_with('['), "{}", json);
+    assert!(json.contains("\"answer\": \"31\""), "{}", json);
+    assert!(json.contains("\"status\": \"solved\""), "{}", json);
+
+    let csv = run("csv");
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!("year,day,part,status,answer,duration_ms", lines[0]);
+    assert!(lines[1].starts_with("2024,1,1,solved,11,"), "{}", csv);
+    assert!(lines[2].starts_with("2024,1,2,solved,31,"), "{}", csv);
+}
+
+#[test]
+fn run_reports_expired_session_without_caching() {
+    let server = MockServer::start();
+    server.route(
+        "GET",
+        "/2024/day/1/input",
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+    );
+    let temp = temp_dir("run-expired");
+    let cache_dir = temp.path();
+
+    let output = run_app(&[
+        "run",
+        "-a",
+        "expired-session",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "--base-url",
+        server.url(),
+        "--cache-dir",
+        cache_dir.to_str().unwrap(),
+    ]);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("The session expired"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(!stderr(&output).contains("panicked"), "{}", stderr(&output));
+    assert!(!cache_dir.join("2024").join("day01.txt").exists());
+}
+
+#[test]
+fn run_reports_locked_day() {
+    let server = MockServer::start();
+    server.route(
+        "GET",
+        "/2024/day/1/input",
+        404,
+        "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n",
+    );
+    let temp = temp_dir("run-locked");
+    let cache_dir = temp.path();
+
+    let output = run_app(&[
+        "run",
+        "-a",
+        "test-session",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "--base-url",
+        server.url(),
+        "--cache-dir",
+        cache_dir.to_str().unwrap(),
+    ]);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("2024 day 1 is not unlocked yet"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(!cache_dir.join("2024").join("day01.txt").exists());
+}
+
+#[test]
+fn run_reports_where_input_is_malformed() {
+    let temp = temp_dir("run-malformed");
+    let cache_dir = temp.path();
+    std::fs::create_dir_all(cache_dir.join("2024")).unwrap();
+    std::fs::write(
+        cache_dir.join("2024").join("day01.txt"),
+        "3   4\n4   3\n2   x\n",
+    )
+    .unwrap();
+    std::fs::write(
+        cache_dir.join("2024").join("day02.txt"),
+        "7 6 4 2 1\n1 2 7 8 9\n",
+    )
+    .unwrap();
+
+    let output = run_app(&[
+        "run",
+        "-y",
+        "2024",
+        "--offline",
+        "--cache-dir",
+        cache_dir.to_str().unwrap(),
+    ]);
+
+    assert!(!output.status.success());
+    let logs = stderr(&output);
+    assert!(
+        logs.contains("2024 day 1 part 1 failed: line 3, column 5: expected a location ID"),
+        "{}",
+        logs
+    );
+    assert!(logs.contains("2 parts failed"), "{}", logs);
+    assert!(!logs.contains("panicked"), "{}", logs);
+    let table = stdout(&output);
+    assert!(table.contains("2024    1     1  failed"), "{}", table);
+    assert!(table.contains("2024    2     1  1 "), "{}", table);
+}
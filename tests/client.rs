@@ -0,0 +1,122 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{run_app_with_env, stderr, temp_dir, MockServer};
+
+fn download(server: &MockServer, cache_dir: &str, day: &str, env: &[(&str, &str)]) -> bool {
+    let output = run_app_with_env(
+        &[
+            "run",
+            "-a",
+            "test-session",
+            "-y",
+            "2024",
+            "-d",
+            day,
+            "--base-url",
+            server.url(),
+            "--cache-dir",
+            cache_dir,
+        ],
+        env,
+    );
+    if !output.status.success() {
+        eprintln!("{}", stderr(&output));
+    }
+    output.status.success()
+}
+
+#[test]
+fn requests_are_identified_and_logged() {
+    let server = MockServer::start();
+    server.route("GET", "/2024/day/1/input", 200, "3   4\n");
+    let temp = temp_dir("client-log");
+    let cache_dir = temp.path();
+
+    assert!(download(&server, cache_dir.to_str().unwrap(), "1", &[]));
+
+    let user_agent = server.requests()[0]
+        .header("User-Agent")
+        .unwrap()
+        .to_string();
+    assert!(
+        user_agent.starts_with("github.com/hmdebenque/advent-of-code-rs/"),
+        "{}",
+        user_agent
+    );
+    let log = std::fs::read_to_string(cache_dir.join("requests.log")).unwrap();
+    assert!(log.ends_with("\tGET\t/2024/day/1/input\t200\n"), "{}", log);
+}
+
+#[test]
+fn transient_failures_are_retried() {
+    let server = MockServer::start();
+    server.route("GET", "/2024/day/1/input", 200, "3   4\n");
+    server.route_once("GET", "/2024/day/1/input", 503, "Service Unavailable");
+    let temp = temp_dir("client-retry");
+    let cache_dir = temp.path();
+
+    assert!(download(&server, cache_dir.to_str().unwrap(), "1", &[]));
+
+    assert_eq!(2, server.requests().len());
+    let log = std::fs::read_to_string(cache_dir.join("requests.log")).unwrap();
+    let outcomes: Vec<&str> = log
+        .lines()
+        .map(|l| l.rsplit('\t').next().unwrap())
+        .collect();
+    assert_eq!(vec!["503", "200"], outcomes);
+}
+
+#[test]
+fn minimum_interval_is_kept_across_runs() {
+    let server = MockServer::start();
+    server.route("GET", "/2024/day/1/input", 200, "3   4\n");
+    server.route("GET", "/2024/day/2/input", 200, "7 6 4 2 1\n");
+    let temp = temp_dir("client-interval");
+    let cache_dir = temp.path();
+    let cache_dir = cache_dir.to_str().unwrap();
+    let interval = [("AOC_MIN_INTERVAL", "1.5")];
+
+    assert!(download(&server, cache_dir, "1", &interval));
+    let now = Instant::now();
+    assert!(download(&server, cache_dir, "2", &interval));
+
+    assert!(
+        now.elapsed() >= Duration::from_secs(1),
+        "{:?}",
+        now.elapsed()
+    );
+    assert_eq!(2, server.requests().len());
+}
+
+#[test]
+fn answers_are_not_sent_again_after_a_failure() {
+    let server = MockServer::start();
+    server.route("POST", "/2024/day/1/answer", 200, "");
+    server.route_once("POST", "/2024/day/1/answer", 503, "Service Unavailable");
+    let temp = temp_dir("client-no-resubmit");
+    let cache_dir = temp.path();
+
+    run_app_with_env(
+        &[
+            "submit",
+            "-a",
+            "test-session",
+            "-y",
+            "2024",
+            "-d",
+            "1",
+            "--base-url",
+            server.url(),
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "-p",
+            "1",
+            "1234",
+        ],
+        &[],
+    );
+
+    assert_eq!(1, server.requests().len());
+}
@@ -0,0 +1,217 @@
+// Stand-in for the AOC website and helpers to run the application against it
+
+#![allow(dead_code)] // each test file only uses part of the helpers
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::{Command, Output};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use tempfile::TempDir;
+
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(String::as_str)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Route {
+    method: String,
+    path: String,
+    status: u16,
+    body: String,
+    /// Number of requests still answered, `None` for no limit
+    remaining: Option<usize>,
+}
+
+/// Minimal HTTP server answering canned responses, unknown routes get a 404
+pub struct MockServer {
+    url: String,
+    routes: Arc<Mutex<Vec<Route>>>,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockServer {
+    pub fn start() -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let routes: Arc<Mutex<Vec<Route>>> = Arc::new(Mutex::new(Vec::new()));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let thread_routes = routes.clone();
+        let thread_requests = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream, &thread_routes, &thread_requests);
+            }
+        });
+
+        MockServer {
+            url,
+            routes,
+            requests,
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Answers `status` and `body` to every request matching `method` and `path`
+    pub fn route(&self, method: &str, path: &str, status: u16, body: &str) -> &MockServer {
+        self.add_route(method, path, status, body, None)
+    }
+
+    /// Answers `status` and `body` to the next request only, then lets the other routes answer
+    pub fn route_once(&self, method: &str, path: &str, status: u16, body: &str) -> &MockServer {
+        self.add_route(method, path, status, body, Some(1))
+    }
+
+    fn add_route(
+        &self,
+        method: &str,
+        path: &str,
+        status: u16,
+        body: &str,
+        remaining: Option<usize>,
+    ) -> &MockServer {
+        self.routes.lock().unwrap().push(Route {
+            method: method.to_string(),
+            path: path.to_string(),
+            status,
+            body: body.to_string(),
+            remaining,
+        });
+        self
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn handle(
+    mut stream: TcpStream,
+    routes: &Mutex<Vec<Route>>,
+    requests: &Mutex<Vec<RecordedRequest>>,
+) {
+    let request = match read_request(&stream) {
+        Some(request) => request,
+        None => return,
+    };
+    let route = routes
+        .lock()
+        .unwrap()
+        .iter_mut()
+        .rev()
+        .find(|r| r.method == request.method && r.path == request.path && r.remaining != Some(0))
+        .map(|r| {
+            r.remaining = r.remaining.map(|remaining| remaining - 1);
+            r.clone()
+        })
+        .unwrap_or(Route {
+            method: request.method.clone(),
+            path: request.path.clone(),
+            status: 404,
+            body: String::from("404 Not Found"),
+            remaining: None,
+        });
+    requests.lock().unwrap().push(request);
+
+    let response = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        route.status,
+        route.body.len(),
+        route.body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+fn read_request(stream: &TcpStream) -> Option<RecordedRequest> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length: usize = headers
+        .get("content-length")
+        .and_then(|l| l.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(RecordedRequest {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).to_string(),
+    })
+}
+
+/// Empty directory dedicated to a test, deleted when dropped
+pub fn temp_dir(name: &str) -> TempDir {
+    tempfile::Builder::new()
+        .prefix(&format!("aoc-it-{}-", name))
+        .tempdir()
+        .unwrap()
+}
+
+/// Runs the application binary with logs enabled
+pub fn run_app(args: &[&str]) -> Output {
+    run_app_with_env(args, &[])
+}
+
+/// Runs the application binary away from the user settings, with only the given variables. It runs
+/// in an empty directory, so the default `inputs` and `fixtures` of the repository are left alone
+pub fn run_app_with_env(args: &[&str], env: &[(&str, &str)]) -> Output {
+    let dir = temp_dir("run");
+    Command::new(env!("CARGO_BIN_EXE_advent-of-code-2023"))
+        .args(args)
+        .current_dir(dir.path())
+        .env("RUST_LOG", "info")
+        .env_remove("AOC_BASE_URL")
+        .env_remove("AOC_SESSION")
+        .env_remove("AOC_SESSION_FILE")
+        .env("AOC_MIN_INTERVAL", "0")
+        .env(
+            "XDG_CONFIG_HOME",
+            std::env::temp_dir().join("aoc-it-no-config"),
+        )
+        .envs(env.iter().copied())
+        .output()
+        .unwrap()
+}
+
+pub fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).to_string()
+}
+
+pub fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::{run_app, stderr, stdout, temp_dir, MockServer};
+
+const PUZZLE_PAGE: &str = "<html><body><main>
+<article class=\"day-desc\"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+</code></pre>
+<p>In the example above, this is <code>2 + 1 + 0 + 1 + 2 + 5</code>, a total distance of <code><em>11</em></code>!</p>
+</article>
+</main></body></html>";
+
+#[test]
+fn examples_downloads_the_description_and_stores_fixtures() {
+    let server = MockServer::start();
+    server.route("GET", "/2024/day/1", 200, PUZZLE_PAGE);
+    let temp = temp_dir("examples-download");
+    let fixtures_dir = temp.path();
+
+    let output = run_app(&[
+        "examples",
+        "-a",
+        "test-session",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "--base-url",
+        server.url(),
+        "--fixtures-dir",
+        fixtures_dir.to_str().unwrap(),
+    ]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(
+        stdout(&output).contains("part 1: 11 for example 1"),
+        "{}",
+        stdout(&output)
+    );
+    assert!(stdout(&output).contains("part 2: no answer found"));
+    let day_dir = fixtures_dir.join("2024").join("day01");
+    assert_eq!(
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3",
+        std::fs::read_to_string(day_dir.join("example1.txt")).unwrap()
+    );
+    assert_eq!(
+        "1\t1\t11\n",
+        std::fs::read_to_string(day_dir.join("answers.txt")).unwrap()
+    );
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn examples_reads_saved_page_without_session() {
+    let temp = temp_dir("examples-saved");
+    let dir = temp.path();
+    std::fs::write(dir.join("day1.html"), PUZZLE_PAGE).unwrap();
+
+    let output = run_app(&[
+        "examples",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "--html",
+        dir.join("day1.html").to_str().unwrap(),
+        "--fixtures-dir",
+        dir.to_str().unwrap(),
+    ]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(dir.join("2024").join("day01").join("answers.txt").exists());
+
+    let empty = run_app(&["examples", "-y", "2024", "-d", "2"]);
+    assert!(!empty.status.success());
+    assert!(stderr(&empty).contains("session"), "{}", stderr(&empty));
+}
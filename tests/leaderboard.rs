@@ -0,0 +1,69 @@
+mod common;
+
+use common::{run_app, stderr, stdout, temp_dir, MockServer};
+
+const LEADERBOARD: &str = include_str!("fixtures/leaderboard-2024.json");
+
+#[test]
+fn leaderboard_from_saved_file() {
+    let output = run_app(&[
+        "leaderboard",
+        "--id",
+        "1234",
+        "-y",
+        "2024",
+        "--file",
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/leaderboard-2024.json"
+        ),
+    ]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let table = stdout(&output);
+    assert!(table.contains("   1      7      3  Alice"), "{}", table);
+    assert!(
+        table.contains("  1  (anonymous user #2)     00:06:40  1d 01:13:20  1d 01:06:40"),
+        "{}",
+        table
+    );
+}
+
+#[test]
+fn leaderboard_is_fetched_at_most_every_15_minutes() {
+    let server = MockServer::start();
+    server.route(
+        "GET",
+        "/2024/leaderboard/private/view/1234.json",
+        200,
+        LEADERBOARD,
+    );
+    let temp = temp_dir("leaderboard");
+    let cache_dir = temp.path();
+    let leaderboard = || {
+        run_app(&[
+            "leaderboard",
+            "-a",
+            "test-session",
+            "--id",
+            "1234",
+            "-y",
+            "2024",
+            "--base-url",
+            server.url(),
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+        ])
+    };
+
+    let first = leaderboard();
+    let second = leaderboard();
+
+    assert!(first.status.success(), "{}", stderr(&first));
+    assert_eq!(stdout(&first), stdout(&second));
+    assert_eq!(1, server.requests().len());
+    assert_eq!(
+        Some("session=test-session"),
+        server.requests()[0].header("Cookie")
+    );
+}
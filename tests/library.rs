@@ -0,0 +1,32 @@
+// The solvers and their toolkit used as a library
+
+use std::str::FromStr;
+
+use advent_of_code_2023::answer::Answer;
+use advent_of_code_2023::common::{CharMatrix, Coordinates2D, Direction};
+use advent_of_code_2023::registry;
+use advent_of_code_2023::solver::Part;
+
+#[test]
+fn registered_solver_from_library() {
+    let registry = registry();
+    let solver = registry.get(2024, 1).unwrap();
+
+    assert_eq!(
+        Some(Answer::Integer(31)),
+        solver
+            .solve(Part::Two, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3")
+            .map(Result::unwrap)
+    );
+}
+
+#[test]
+fn common_toolkit_from_library() {
+    let matrix = CharMatrix::from_str("ab\ncd").unwrap();
+    let start = Coordinates2D::from_str("0,0").unwrap();
+
+    assert_eq!(
+        Some('c'),
+        matrix.get_char_at(&start.advance(Direction::South))
+    );
+}
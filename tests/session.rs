@@ -0,0 +1,111 @@
+mod common;
+
+use common::{run_app_with_env, stderr, temp_dir, MockServer};
+
+const SESSION: &str = "53616c7465645f5f";
+
+fn download(server: &MockServer, name: &str, extra: &[&str], env: &[(&str, &str)]) -> String {
+    server.route("GET", "/2024/day/1/input", 200, "3   4\n");
+    let temp = temp_dir(name);
+    let cache_dir = temp.path();
+    let mut args = vec![
+        "run",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "--base-url",
+        server.url(),
+        "--cache-dir",
+        cache_dir.to_str().unwrap(),
+    ];
+    args.extend_from_slice(extra);
+    let output = run_app_with_env(&args, env);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let logs = stderr(&output);
+    assert!(!logs.contains(SESSION), "{}", logs);
+    server.requests()[0].header("Cookie").unwrap().to_string()
+}
+
+#[test]
+fn session_from_environment() {
+    let server = MockServer::start();
+
+    let cookie = download(&server, "session-env", &[], &[("AOC_SESSION", SESSION)]);
+
+    assert_eq!(format!("session={}", SESSION), cookie);
+}
+
+#[test]
+fn session_from_flag_is_not_logged() {
+    let server = MockServer::start();
+
+    let cookie = download(&server, "session-flag", &["-a", SESSION], &[]);
+
+    assert_eq!(format!("session={}", SESSION), cookie);
+}
+
+#[test]
+fn session_from_configuration() {
+    let server = MockServer::start();
+    let temp = temp_dir("session-config");
+    let config_home = temp.path();
+    std::fs::create_dir_all(config_home.join("aoc")).unwrap();
+    std::fs::write(
+        config_home.join("aoc").join("config.toml"),
+        format!("session = \"{}\"\n", SESSION),
+    )
+    .unwrap();
+
+    let cookie = download(
+        &server,
+        "session-config-cache",
+        &[],
+        &[("XDG_CONFIG_HOME", config_home.to_str().unwrap())],
+    );
+
+    assert_eq!(format!("session={}", SESSION), cookie);
+}
+
+#[test]
+fn session_from_token_file() {
+    let server = MockServer::start();
+    let temp = temp_dir("session-file");
+    let dir = temp.path();
+    std::fs::write(dir.join("aoc-token"), format!("{}\n", SESSION)).unwrap();
+
+    let cookie = download(
+        &server,
+        "session-file-cache",
+        &["--session-file", dir.join("aoc-token").to_str().unwrap()],
+        &[],
+    );
+
+    assert_eq!(format!("session={}", SESSION), cookie);
+}
+
+#[test]
+fn missing_session_is_reported() {
+    let temp = temp_dir("session-missing");
+    let cache_dir = temp.path();
+
+    let output = run_app_with_env(
+        &[
+            "run",
+            "-y",
+            "2024",
+            "-d",
+            "1",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+        ],
+        &[],
+    );
+
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("AOC_SESSION"),
+        "{}",
+        stderr(&output)
+    );
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::{run_app, stderr, stdout, temp_dir, MockServer};
+
+const TOO_HIGH: &str = "<html><body><main>
+<article><p>That's not the right answer; your answer is too high.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>
+</main></body></html>";
+
+const CORRECT: &str = "<html><body><main>
+<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian. <a href=\"/2024/day/1#part2\">[Continue to Part Two]</a></p></article>
+</main></body></html>";
+
+const RATE_LIMITED: &str = "<html><body><main>
+<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 45s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>
+</main></body></html>";
+
+fn submit(server: &MockServer, cache_dir: &str, extra: &[&str]) -> std::process::Output {
+    let mut args = vec![
+        "submit",
+        "-a",
+        "test-session",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "--base-url",
+        server.url(),
+        "--cache-dir",
+        cache_dir,
+    ];
+    args.extend_from_slice(extra);
+    run_app(&args)
+}
+
+#[test]
+fn submit_classifies_and_never_resends_a_wrong_answer() {
+    let server = MockServer::start();
+    server.route("POST", "/2024/day/1/answer", 200, TOO_HIGH);
+    let temp = temp_dir("submit-wrong");
+    let cache_dir = temp.path();
+    let cache_dir = cache_dir.to_str().unwrap();
+
+    let first = submit(&server, cache_dir, &["-p", "1", "1234"]);
+    assert!(first.status.success(), "{}", stderr(&first));
+    assert!(stdout(&first).contains("too high"), "{}", stdout(&first));
+
+    let second = submit(&server, cache_dir, &["-p", "1", "1234"]);
+    assert!(second.status.success(), "{}", stderr(&second));
+    assert!(
+        stdout(&second).contains("already submitted"),
+        "{}",
+        stdout(&second)
+    );
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("level=1&answer=1234", requests[0].body);
+    assert_eq!(Some("session=test-session"), requests[0].header("Cookie"));
+}
+
+#[test]
+fn submit_resends_after_rate_limit() {
+    let server = MockServer::start();
+    server.route("POST", "/2024/day/1/answer", 200, RATE_LIMITED);
+    let temp = temp_dir("submit-rate-limited");
+    let cache_dir = temp.path();
+    let cache_dir = cache_dir.to_str().unwrap();
+
+    let first = submit(&server, cache_dir, &["-p", "2", "31"]);
+    assert!(stdout(&first).contains("wait 45s"), "{}", stdout(&first));
+
+    submit(&server, cache_dir, &["-p", "2", "31"]);
+
+    assert_eq!(2, server.requests().len());
+}
+
+#[test]
+fn submit_solves_when_no_answer_given() {
+    let server = MockServer::start();
+    server.route(
+        "GET",
+        "/2024/day/1/input",
+        200,
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+    );
+    server.route("POST", "/2024/day/1/answer", 200, TOO_HIGH);
+    let temp = temp_dir("submit-solve");
+    let cache_dir = temp.path();
+
+    let output = submit(&server, cache_dir.to_str().unwrap(), &["-p", "2"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let requests = server.requests();
+    assert_eq!(2, requests.len());
+    assert_eq!("level=2&answer=31", requests[1].body);
+}
+
+#[test]
+fn submit_records_correct_answer() {
+    let server = MockServer::start();
+    server.route("POST", "/2024/day/1/answer", 200, CORRECT);
+    let temp = temp_dir("submit-correct");
+    let cache_dir = temp.path();
+
+    let output = submit(&server, cache_dir.to_str().unwrap(), &["-p", "1", "11"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(
+        stdout(&output).contains("right answer"),
+        "{}",
+        stdout(&output)
+    );
+    assert_eq!(
+        "2024\t1\t1\t11\n",
+        std::fs::read_to_string(cache_dir.join("answers.txt")).unwrap()
+    );
+}
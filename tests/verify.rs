@@ -0,0 +1,73 @@
+mod common;
+
+use common::{run_app, stderr, stdout, temp_dir};
+
+const INPUT_2024_1: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+#[test]
+fn verify_reports_mismatches() {
+    let temp = temp_dir("verify");
+    let cache_dir = temp.path();
+    std::fs::create_dir_all(cache_dir.join("2024")).unwrap();
+    std::fs::write(cache_dir.join("2024").join("day01.txt"), INPUT_2024_1).unwrap();
+    let cache_dir = cache_dir.to_str().unwrap();
+
+    let recorded = run_app(&[
+        "record",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "-p",
+        "1",
+        "11",
+        "--cache-dir",
+        cache_dir,
+    ]);
+    assert!(recorded.status.success(), "{}", stderr(&recorded));
+    run_app(&[
+        "record",
+        "-y",
+        "2024",
+        "-d",
+        "1",
+        "-p",
+        "2",
+        "32",
+        "--cache-dir",
+        cache_dir,
+    ]);
+
+    let output = run_app(&["verify", "--year", "2024", "--cache-dir", cache_dir]);
+
+    assert!(!output.status.success());
+    let report = stdout(&output);
+    assert!(report.contains("2024 day  1 part 1: ok"), "{}", report);
+    assert!(
+        report.contains("2024 day  1 part 2: MISMATCH expected 32 but got 31"),
+        "{}",
+        report
+    );
+    assert!(
+        report.contains("2 answers checked, 1 mismatches"),
+        "{}",
+        report
+    );
+}
+
+#[test]
+fn verify_succeeds_without_answers() {
+    let temp = temp_dir("verify-empty");
+    let cache_dir = temp.path();
+
+    let output = run_app(&["verify", "--cache-dir", cache_dir.to_str().unwrap()]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("0 answers checked, 0 mismatches"));
+}